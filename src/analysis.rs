@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::solver::{
+    GameResult, MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue,
+    RmtU32, Solver, ToGameResult, ToRecursiveValue, ToRmt,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct GameResultCounts {
+    pub wins: usize,
+    pub loses: usize,
+    pub ties: usize,
+    pub draws: usize,
+}
+
+impl GameResultCounts {
+    fn add(&mut self, game_result: GameResult) {
+        match game_result {
+            GameResult::Win => self.wins += 1,
+            GameResult::Lose => self.loses += 1,
+            GameResult::Tie => self.ties += 1,
+            GameResult::Draw => self.draws += 1,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.wins + self.loses + self.ties + self.draws
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"win\":{},\"lose\":{},\"tie\":{},\"draw\":{},\"total\":{}}}",
+            self.wins,
+            self.loses,
+            self.ties,
+            self.draws,
+            self.total()
+        )
+    }
+}
+
+/// Counts of solved positions by value, in the style of GamesmanClassic's
+/// analysis screen.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Analysis {
+    pub all: GameResultCounts,
+    pub primitive: GameResultCounts,
+    /// Empty for values that do not track remoteness.
+    pub by_rmt: BTreeMap<RmtU32, GameResultCounts>,
+}
+
+impl Analysis {
+    pub fn new<P, M, PV, RV, S>(solver: &Solver<P, M, PV, RV, S>) -> Self
+    where
+        P: Position<M, PV> + PositionKey,
+        M: PlayerMove,
+        PV: PrimitiveValue + ToRecursiveValue<RV>,
+        RV: RecursiveValue + ToGameResult + ToRmt,
+        S: MemoStore<P, RV>,
+    {
+        let mut analysis = Analysis::default();
+
+        solver.for_each_solved(|position, result| {
+            let game_result = result.to_game_result();
+
+            analysis.all.add(game_result);
+
+            if position.primitive_value().is_primitive() {
+                analysis.primitive.add(game_result);
+            }
+
+            if let Some(rmt) = result.to_rmt() {
+                analysis.by_rmt.entry(rmt).or_default().add(game_result);
+            }
+        });
+
+        analysis
+    }
+
+    pub fn to_json(&self) -> String {
+        let by_rmt = self
+            .by_rmt
+            .iter()
+            .map(|(rmt, counts)| {
+                let rmt = match rmt {
+                    RmtU32::Val(v) => v.to_string(),
+                    RmtU32::Inf => "\"inf\"".to_string(),
+                };
+
+                format!("{{\"remoteness\":{},\"counts\":{}}}", rmt, counts.to_json())
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "{{\"all\":{},\"primitive\":{},\"by_remoteness\":[{}]}}",
+            self.all.to_json(),
+            self.primitive.to_json(),
+            by_rmt
+        )
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row = |f: &mut fmt::Formatter<'_>, label: &str, counts: &GameResultCounts| {
+            writeln!(
+                f,
+                "{:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                label,
+                counts.wins,
+                counts.loses,
+                counts.ties,
+                counts.draws,
+                counts.total()
+            )
+        };
+
+        writeln!(
+            f,
+            "{:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "Remoteness", "Win", "Lose", "Tie", "Draw", "Total"
+        )?;
+
+        for (rmt, counts) in self.by_rmt.iter() {
+            let label = match rmt {
+                RmtU32::Val(v) => v.to_string(),
+                RmtU32::Inf => "Inf".to_string(),
+            };
+
+            row(f, &label, counts)?;
+        }
+
+        row(f, "Primitive", &self.primitive)?;
+        row(f, "Total", &self.all)
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use super::{Analysis, GameResultCounts};
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver};
+
+    #[test]
+    fn tic_tac_toe() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        solver.solve(TicTacToePosition::start());
+
+        let analysis = Analysis::new(&solver);
+
+        assert_eq!(
+            analysis.all,
+            GameResultCounts {
+                wins: 2836,
+                loses: 1574,
+                ties: 1068,
+                draws: 0,
+            }
+        );
+        assert_eq!(
+            analysis.primitive,
+            GameResultCounts {
+                wins: 0,
+                loses: 942,
+                ties: 16,
+                draws: 0,
+            }
+        );
+        assert!(analysis.by_rmt.is_empty());
+        assert_eq!(5478, analysis.all.total());
+
+        println!("{}", analysis);
+    }
+
+    #[test]
+    fn tic_tac_toe_d4_by_rmt() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        solver.solve(TicTacToePositionD4Eq::new(TicTacToePosition::start()));
+
+        let analysis = Analysis::new(&solver);
+
+        assert_eq!(765, analysis.all.total());
+        assert_eq!(390, analysis.all.wins);
+        assert_eq!(224, analysis.all.loses);
+        assert_eq!(151, analysis.all.ties);
+
+        assert_eq!(
+            analysis.all.total(),
+            analysis.by_rmt.values().map(|c| c.total()).sum::<usize>()
+        );
+        assert_eq!(
+            analysis.primitive.total(),
+            analysis.by_rmt[&RmtU32::Val(0)].total()
+        );
+        assert_eq!(1, analysis.by_rmt[&RmtU32::Val(9)].total());
+
+        println!("{}", analysis);
+    }
+
+    #[test]
+    fn table_and_json() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        solver.solve(take_10_to_0::position(4));
+
+        let analysis = Analysis::new(&solver);
+
+        assert_eq!(
+            analysis.to_string(),
+            [
+                "Remoteness        Win       Lose        Tie       Draw      Total",
+                "         0          0          1          0          0          1",
+                "         1          2          0          0          0          2",
+                "         2          0          1          0          0          1",
+                "         3          1          0          0          0          1",
+                " Primitive          0          1          0          0          1",
+                "     Total          3          2          0          0          5",
+                "",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            analysis.to_json(),
+            concat!(
+                "{\"all\":{\"win\":3,\"lose\":2,\"tie\":0,\"draw\":0,\"total\":5},",
+                "\"primitive\":{\"win\":0,\"lose\":1,\"tie\":0,\"draw\":0,\"total\":1},",
+                "\"by_remoteness\":[",
+                "{\"remoteness\":0,\"counts\":{\"win\":0,\"lose\":1,\"tie\":0,\"draw\":0,\"total\":1}},",
+                "{\"remoteness\":1,\"counts\":{\"win\":2,\"lose\":0,\"tie\":0,\"draw\":0,\"total\":2}},",
+                "{\"remoteness\":2,\"counts\":{\"win\":0,\"lose\":1,\"tie\":0,\"draw\":0,\"total\":1}},",
+                "{\"remoteness\":3,\"counts\":{\"win\":1,\"lose\":0,\"tie\":0,\"draw\":0,\"total\":1}}",
+                "]}"
+            )
+        );
+    }
+}
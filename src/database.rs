@@ -0,0 +1,361 @@
+//! Solved games on disk.
+//!
+//! A database file is a header followed by the dense `ArrayStore` layout of
+//! every solved position, so it can only hold games with a `PositionHash`.
+//! The layout is cut into blocks of `block size` consecutive indices, each
+//! LZ77-coded on its own, so a lookup only decompresses one block:
+//!
+//! | Field          | Encoding                                     |
+//! |----------------|----------------------------------------------|
+//! | Magic          | `b"GCDB"`                                    |
+//! | Version        | `u16`                                        |
+//! | Game id        | `u16` length, then UTF-8 bytes               |
+//! | Variant params | `u16` count, then one `u64` each             |
+//! | Value type     | `u16` length, then UTF-8 bytes               |
+//! | Value bits     | `u8`                                         |
+//! | Index count    | `u64`                                        |
+//! | Solved count   | `u64`                                        |
+//! | Block size     | `u32`, a multiple of 64                      |
+//! | Block offsets  | one `u64` per block, then the end offset     |
+//! | Blocks         | compressed blocks, back to back              |
+//! | Checksum       | `u64` FNV-1a of everything before it         |
+//!
+//! Offsets are relative to the first block. A block decompresses to its
+//! share of the solved bitset, one `u64` per 64 indices, followed by its
+//! share of the values, `u64` words each holding `64 / bits` values; the
+//! last block is zero-padded. All integers are little-endian.
+
+mod error;
+mod format;
+mod lz;
+mod mapped;
+mod mmap;
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+pub use self::error::DatabaseError;
+pub use self::format::{CompressionStats, DatabaseHeader};
+pub use self::mapped::MappedDatabase;
+
+use crate::solver::{
+    ArrayStore, MemoStore, PackedValue, PlayerMove, Position, PositionHash, PositionKey,
+    PrimitiveValue, RecursiveValue, Solver, ToRecursiveValue,
+};
+
+pub const MAGIC: [u8; 4] = *b"GCDB";
+
+pub const VERSION: u16 = 2;
+
+/// The block size new databases are written with.
+pub const BLOCK_INDICES: u32 = 4096;
+
+/// Positions that can be saved to and loaded from a database file.
+pub trait DatabasePosition: PositionHash {
+    /// Identifies the game in the header, e.g. `"tic_tac_toe"`.
+    fn game_id() -> String;
+
+    /// Whatever the header needs to tell variants apart, e.g. the board
+    /// dimensions.
+    fn variant_params(variant: &Self::Variant) -> Vec<u64>;
+}
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey + DatabasePosition + Clone,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue + PackedValue,
+    S: MemoStore<P, RV>,
+{
+    /// Fails without writing anything if a solved position is not of
+    /// `variant`.
+    pub fn write_database(
+        &self,
+        variant: P::Variant,
+        writer: impl Write,
+    ) -> Result<CompressionStats, DatabaseError> {
+        let variant_params = P::variant_params(&variant);
+        let index_count = P::index_count(&variant);
+        let mut store = ArrayStore::<P, RV>::new(variant);
+        let mut error = None;
+
+        self.for_each_solved(|position, result| {
+            if error.is_some() {
+                return;
+            }
+
+            let found = P::variant_params(&position.variant());
+
+            if found != variant_params {
+                error = Some(DatabaseError::VariantMismatch {
+                    expected: variant_params.clone(),
+                    found,
+                });
+            } else if position.to_index() >= index_count {
+                error = Some(DatabaseError::IndexOutOfRange {
+                    index: position.to_index(),
+                    index_count,
+                });
+            } else {
+                store.insert(position.clone(), result.clone());
+            }
+        });
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        format::write_store(&store, writer)
+    }
+
+    pub fn save_database(
+        &self,
+        variant: P::Variant,
+        path: impl AsRef<Path>,
+    ) -> Result<CompressionStats, DatabaseError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        let stats = self.write_database(variant, &mut writer)?;
+        writer.flush()?;
+
+        Ok(stats)
+    }
+}
+
+/// Reads a database written for `variant` into a store that `Solver::new`
+/// can be seeded with.
+pub fn read_database<P, RV>(
+    variant: P::Variant,
+    reader: impl Read,
+) -> Result<ArrayStore<P, RV>, DatabaseError>
+where
+    P: DatabasePosition,
+    RV: PackedValue,
+{
+    format::read_store(variant, reader)
+}
+
+pub fn load_database<P, RV>(
+    variant: P::Variant,
+    path: impl AsRef<Path>,
+) -> Result<ArrayStore<P, RV>, DatabaseError>
+where
+    P: DatabasePosition,
+    RV: PackedValue,
+{
+    read_database(variant, BufReader::new(File::open(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+
+    use super::{
+        load_database, read_database, CompressionStats, DatabaseError, DatabaseHeader,
+        DatabasePosition, MAGIC,
+    };
+    use crate::games::connect_four::ConnectFourPosition;
+    use crate::games::mnk::{MnkPosition, MnkPositionSymEq};
+    use crate::games::order_and_chaos::OrderAndChaosGame;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::solver::{
+        GameResult, GameResultWithRmt, MemoStore, PlayerMove, Position, PositionHash, PositionKey,
+        PrimitiveValue, Solver, ToRecursiveValue,
+    };
+
+    fn tic_tac_toe() -> TicTacToePositionD4Eq {
+        TicTacToePositionD4Eq::new(TicTacToePosition::start())
+    }
+
+    fn mnk_database() -> Vec<u8> {
+        let start = MnkPosition::start(3, 2, 2);
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        solver.solve(start.clone());
+
+        let mut bytes = vec![];
+        solver.write_database(start.variant(), &mut bytes).unwrap();
+
+        bytes
+    }
+
+    fn compression_stats<P, M, PV>(start: P) -> CompressionStats
+    where
+        P: Position<M, PV> + PositionKey + DatabasePosition + Clone,
+        M: PlayerMove,
+        PV: PrimitiveValue + ToRecursiveValue<GameResultWithRmt>,
+    {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        solver.solve(start.clone());
+
+        let mut bytes = vec![];
+        let stats = solver.write_database(start.variant(), &mut bytes).unwrap();
+
+        let loaded = read_database::<P, GameResultWithRmt>(start.variant(), &bytes[..]);
+        assert_eq!(loaded.unwrap().len(), solver.len());
+
+        stats
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!(
+            "gamescrafters-save-and-load-{}.db",
+            std::process::id()
+        ));
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let result = solver.solve(tic_tac_toe());
+        solver.save_database((), &path).unwrap();
+
+        let store = load_database::<TicTacToePositionD4Eq, GameResultWithRmt>((), &path);
+        fs::remove_file(&path).unwrap();
+
+        let mut loaded = Solver::<_, _, _, GameResultWithRmt, _>::new(store.unwrap());
+
+        assert_eq!(loaded.len(), solver.len());
+        assert_eq!(loaded.solved_value(&tic_tac_toe()), Some(result));
+
+        for (position, result) in &solver {
+            assert_eq!(loaded.solved_value(position).as_ref(), Some(result));
+        }
+
+        assert_eq!(loaded.solve(tic_tac_toe()), result);
+        assert_eq!(loaded.len(), solver.len());
+    }
+
+    #[test]
+    fn header() {
+        let bytes = mnk_database();
+
+        assert_eq!(
+            DatabaseHeader::read(&mut &bytes[..]).unwrap(),
+            DatabaseHeader {
+                game_id: "mnk".to_string(),
+                variant_params: vec![3, 2, 2],
+                value_type: "game_result_with_rmt".to_string(),
+                value_bits: 16,
+                index_count: 3u64.pow(6),
+                len: 129,
+                block_indices: 4096,
+            }
+        );
+    }
+
+    #[test]
+    fn compression_ratio_per_game() {
+        let games = [
+            (
+                "tic_tac_toe",
+                compression_stats(tic_tac_toe().into_position()),
+            ),
+            ("tic_tac_toe/d4", compression_stats(tic_tac_toe())),
+            ("mnk 4x2 k3", compression_stats(MnkPosition::start(4, 2, 3))),
+            (
+                "order_and_chaos 3x3 k3",
+                compression_stats(OrderAndChaosGame::new(3, 3, 3).start()),
+            ),
+            (
+                "connect_four 4x3 k3",
+                compression_stats(ConnectFourPosition::start(4, 3, 3)),
+            ),
+        ];
+
+        for (game, stats) in games {
+            println!("{game}: {stats}");
+
+            // Indices are dense enough that most of each table is reachable,
+            // so tables without symmetry only shrink by a small factor.
+            assert!(stats.ratio() > 1.5, "{game} compressed poorly: {stats}");
+        }
+    }
+
+    #[test]
+    fn mismatched_database() {
+        let bytes = mnk_database();
+        let variant = MnkPosition::start(3, 2, 2).variant();
+
+        assert!(read_database::<MnkPosition, GameResultWithRmt>(variant, &bytes[..]).is_ok());
+        assert!(matches!(
+            read_database::<MnkPositionSymEq, GameResultWithRmt>(variant, &bytes[..]),
+            Err(DatabaseError::GameMismatch { .. })
+        ));
+        assert!(matches!(
+            read_database::<MnkPosition, GameResultWithRmt>(
+                MnkPosition::start(2, 3, 2).variant(),
+                &bytes[..]
+            ),
+            Err(DatabaseError::VariantMismatch { .. })
+        ));
+        assert!(matches!(
+            read_database::<MnkPosition, GameResult>(variant, &bytes[..]),
+            Err(DatabaseError::ValueTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            read_database::<TicTacToePosition, GameResultWithRmt>((), &bytes[..]),
+            Err(DatabaseError::GameMismatch { .. })
+        ));
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        solver.solve(MnkPosition::start(3, 3, 3));
+
+        assert!(matches!(
+            solver.write_database(variant, &mut vec![]),
+            Err(DatabaseError::VariantMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn corrupt_database() {
+        let bytes = mnk_database();
+        let variant = MnkPosition::start(3, 2, 2).variant();
+        let read = |bytes: &[u8]| read_database::<MnkPosition, GameResultWithRmt>(variant, bytes);
+
+        assert!(matches!(read(&[]), Err(DatabaseError::Truncated)));
+        assert!(matches!(
+            read(&bytes[..bytes.len() - 1]),
+            Err(DatabaseError::Truncated)
+        ));
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(matches!(read(&bad_magic), Err(DatabaseError::NotADatabase)));
+
+        let mut bad_version = bytes.clone();
+        bad_version[MAGIC.len()] = 99;
+        assert!(matches!(
+            read(&bad_version),
+            Err(DatabaseError::UnsupportedVersion(99))
+        ));
+
+        let mut flipped = bytes.clone();
+        let last_value = flipped.len() - 9;
+        flipped[last_value] ^= 1;
+        assert!(matches!(
+            read(&flipped),
+            Err(DatabaseError::ChecksumMismatch)
+        ));
+
+        let mut header_end = &bytes[..];
+        DatabaseHeader::read(&mut header_end).unwrap();
+
+        let mut bad_offsets = bytes.clone();
+        bad_offsets[bytes.len() - header_end.len()] = 1;
+        assert!(matches!(
+            read(&bad_offsets),
+            Err(DatabaseError::Corrupt("invalid block offsets"))
+        ));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(read(&trailing), Err(DatabaseError::Corrupt(_))));
+
+        assert_eq!(
+            read(&flipped).unwrap_err().to_string(),
+            "database checksum does not match"
+        );
+    }
+}
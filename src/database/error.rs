@@ -0,0 +1,82 @@
+use std::{error::Error, fmt, io};
+
+#[derive(Debug)]
+pub enum DatabaseError {
+    Io(io::Error),
+    /// The file ended before everything the header promised was read.
+    Truncated,
+    NotADatabase,
+    UnsupportedVersion(u16),
+    GameMismatch {
+        expected: String,
+        found: String,
+    },
+    VariantMismatch {
+        expected: Vec<u64>,
+        found: Vec<u64>,
+    },
+    ValueTypeMismatch {
+        expected: String,
+        found: String,
+    },
+    /// The data is well-formed but inconsistent with itself.
+    Corrupt(&'static str),
+    ChecksumMismatch,
+    /// A position being written has an index past its variant's index
+    /// count, so its `PositionHash` is broken.
+    IndexOutOfRange {
+        index: u64,
+        index_count: u64,
+    },
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseError::Io(error) => write!(f, "I/O error: {error}"),
+            DatabaseError::Truncated => write!(f, "database file is truncated"),
+            DatabaseError::NotADatabase => write!(f, "not a database file"),
+            DatabaseError::UnsupportedVersion(version) => {
+                write!(f, "unsupported database version {version}")
+            }
+            DatabaseError::GameMismatch { expected, found } => {
+                write!(f, "database is for game {found:?}, expected {expected:?}")
+            }
+            DatabaseError::VariantMismatch { expected, found } => {
+                write!(
+                    f,
+                    "database is for variant {found:?}, expected {expected:?}"
+                )
+            }
+            DatabaseError::ValueTypeMismatch { expected, found } => {
+                write!(f, "database holds {found:?} values, expected {expected:?}")
+            }
+            DatabaseError::Corrupt(reason) => write!(f, "database is corrupt: {reason}"),
+            DatabaseError::ChecksumMismatch => write!(f, "database checksum does not match"),
+            DatabaseError::IndexOutOfRange { index, index_count } => {
+                write!(
+                    f,
+                    "position index {index} is past the index count {index_count}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DatabaseError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DatabaseError {
+    fn from(error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::UnexpectedEof => DatabaseError::Truncated,
+            _ => DatabaseError::Io(error),
+        }
+    }
+}
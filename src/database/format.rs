@@ -0,0 +1,443 @@
+use std::io::{self, Read, Write};
+
+use std::fmt;
+
+use super::{lz, DatabaseError, DatabasePosition, BLOCK_INDICES, MAGIC, VERSION};
+use crate::solver::{ArrayStore, MemoStore, PackedValue};
+
+/// Bounds a corrupt header's block size before anything is allocated for it.
+const MAX_BLOCK_INDICES: u32 = 1 << 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DatabaseHeader {
+    pub game_id: String,
+    pub variant_params: Vec<u64>,
+    pub value_type: String,
+    pub value_bits: u8,
+    pub index_count: u64,
+    pub len: u64,
+    pub block_indices: u32,
+}
+
+impl DatabaseHeader {
+    pub fn new<P, RV>(variant: &P::Variant, len: u64) -> Self
+    where
+        P: DatabasePosition,
+        RV: PackedValue,
+    {
+        DatabaseHeader {
+            game_id: P::game_id(),
+            variant_params: P::variant_params(variant),
+            value_type: RV::NAME.to_string(),
+            value_bits: RV::BITS as u8,
+            index_count: P::index_count(variant),
+            len,
+            block_indices: BLOCK_INDICES,
+        }
+    }
+
+    pub fn read(reader: &mut impl Read) -> Result<Self, DatabaseError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if magic != MAGIC {
+            return Err(DatabaseError::NotADatabase);
+        }
+
+        let version = read_u16(reader)?;
+
+        if version != VERSION {
+            return Err(DatabaseError::UnsupportedVersion(version));
+        }
+
+        let game_id = read_string(reader)?;
+
+        let variant_params = (0..read_u16(reader)?)
+            .map(|_| read_u64(reader))
+            .collect::<Result<_, _>>()?;
+
+        let value_type = read_string(reader)?;
+
+        let mut value_bits = [0; 1];
+        reader.read_exact(&mut value_bits)?;
+
+        Ok(DatabaseHeader {
+            game_id,
+            variant_params,
+            value_type,
+            value_bits: value_bits[0],
+            index_count: read_u64(reader)?,
+            len: read_u64(reader)?,
+            block_indices: read_u32(reader)?,
+        })
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        write_string(writer, &self.game_id)?;
+        writer.write_all(&(self.variant_params.len() as u16).to_le_bytes())?;
+
+        for param in &self.variant_params {
+            writer.write_all(&param.to_le_bytes())?;
+        }
+
+        write_string(writer, &self.value_type)?;
+        writer.write_all(&[self.value_bits])?;
+        writer.write_all(&self.index_count.to_le_bytes())?;
+        writer.write_all(&self.len.to_le_bytes())?;
+        writer.write_all(&self.block_indices.to_le_bytes())
+    }
+
+    /// Checks that this header describes a database of `P` and `RV` for
+    /// `variant`.
+    pub fn check<P, RV>(&self, variant: &P::Variant) -> Result<(), DatabaseError>
+    where
+        P: DatabasePosition,
+        RV: PackedValue,
+    {
+        let expected = DatabaseHeader::new::<P, RV>(variant, self.len);
+
+        if self.game_id != expected.game_id {
+            return Err(DatabaseError::GameMismatch {
+                expected: expected.game_id,
+                found: self.game_id.clone(),
+            });
+        }
+
+        if self.variant_params != expected.variant_params {
+            return Err(DatabaseError::VariantMismatch {
+                expected: expected.variant_params,
+                found: self.variant_params.clone(),
+            });
+        }
+
+        if self.value_type != expected.value_type {
+            return Err(DatabaseError::ValueTypeMismatch {
+                expected: expected.value_type,
+                found: self.value_type.clone(),
+            });
+        }
+
+        if self.value_bits != expected.value_bits {
+            return Err(DatabaseError::Corrupt("value bits do not match value type"));
+        }
+
+        if self.index_count != expected.index_count {
+            return Err(DatabaseError::Corrupt("index count does not match variant"));
+        }
+
+        if self.len > self.index_count {
+            return Err(DatabaseError::Corrupt("more solved positions than indices"));
+        }
+
+        if self.block_indices == 0
+            || !self.block_indices.is_multiple_of(64)
+            || self.block_indices > MAX_BLOCK_INDICES
+        {
+            return Err(DatabaseError::Corrupt("invalid block size"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Sizes of a database's table before and after compression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionStats {
+    /// The solved bitset and packed values, as `ArrayStore` holds them.
+    pub table_bytes: u64,
+    /// The compressed blocks plus the offsets locating them.
+    pub compressed_bytes: u64,
+}
+
+impl CompressionStats {
+    pub fn ratio(&self) -> f64 {
+        self.table_bytes as f64 / self.compressed_bytes as f64
+    }
+}
+
+impl fmt::Display for CompressionStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes compressed to {} bytes ({:.2}x)",
+            self.table_bytes,
+            self.compressed_bytes,
+            self.ratio()
+        )
+    }
+}
+
+/// How a table is cut into blocks of `block_indices` positions. Block `i`
+/// decompresses to its share of the solved bitset followed by its share of
+/// the values, zero-padded past the end of the table.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct BlockLayout {
+    pub(super) block_indices: usize,
+    pub(super) block_count: usize,
+    solved_words: usize,
+    value_words: usize,
+}
+
+impl BlockLayout {
+    /// Only call on a header that passed `check`.
+    pub(super) fn new(header: &DatabaseHeader) -> Self {
+        let block_indices = header.block_indices as usize;
+        BlockLayout {
+            block_indices,
+            block_count: (header.index_count as usize).div_ceil(block_indices),
+            solved_words: block_indices / 64,
+            value_words: block_indices * header.value_bits as usize / 64,
+        }
+    }
+
+    fn block_bytes(&self) -> usize {
+        (self.solved_words + self.value_words) * 8
+    }
+
+    fn encode(&self, solved: &[u64], values: &[u64], block: usize, output: &mut Vec<u8>) {
+        let mut bytes = Vec::with_capacity(self.block_bytes());
+
+        for (words, block_words) in [(solved, self.solved_words), (values, self.value_words)] {
+            let start = (block * block_words).min(words.len());
+            let end = (start + block_words).min(words.len());
+
+            for word in &words[start..end] {
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+
+            bytes.resize(bytes.len() + (block_words - (end - start)) * 8, 0);
+        }
+
+        lz::encode(&bytes, output);
+    }
+
+    /// The solved words and value words of one compressed block.
+    pub(super) fn decode(&self, block: &[u8]) -> Result<(Vec<u64>, Vec<u64>), DatabaseError> {
+        let bytes = lz::decode(block, self.block_bytes())?;
+
+        let mut words = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("chunk should be 8 bytes")));
+
+        let solved = words.by_ref().take(self.solved_words).collect();
+
+        Ok((solved, words.collect()))
+    }
+
+    /// Reads the offset of every block, plus one past the last, relative to
+    /// the first.
+    pub(super) fn read_offsets(&self, reader: &mut impl Read) -> Result<Vec<u64>, DatabaseError> {
+        // Coding grows a block by at most one byte per 255 literals and a
+        // token, so anything past twice the size is corrupt.
+        let max_block_bytes = 2 * self.block_bytes() as u64;
+
+        let offsets = (0..=self.block_count)
+            .map(|_| read_u64(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if offsets[0] != 0
+            || offsets
+                .windows(2)
+                .any(|pair| pair[1] < pair[0] || pair[1] - pair[0] > max_block_bytes)
+        {
+            return Err(DatabaseError::Corrupt("invalid block offsets"));
+        }
+
+        Ok(offsets)
+    }
+}
+
+pub(super) fn write_store<P, RV>(
+    store: &ArrayStore<P, RV>,
+    writer: impl Write,
+) -> Result<CompressionStats, DatabaseError>
+where
+    P: DatabasePosition,
+    RV: PackedValue,
+{
+    let mut writer = Fnv1aWriter::new(writer);
+
+    let header = DatabaseHeader::new::<P, RV>(store.variant(), store.len() as u64);
+    header.write(&mut writer)?;
+
+    let layout = BlockLayout::new(&header);
+    let (solved, values) = store.words();
+
+    // The offsets go first, so the blocks are compressed before anything
+    // else is written.
+    let mut blocks = vec![];
+    let mut offsets = vec![0];
+
+    for block in 0..layout.block_count {
+        layout.encode(solved, values, block, &mut blocks);
+        offsets.push(blocks.len() as u64);
+    }
+
+    for offset in &offsets {
+        writer.write_all(&offset.to_le_bytes())?;
+    }
+
+    writer.write_all(&blocks)?;
+
+    let checksum = writer.hash;
+    writer.inner.write_all(&checksum.to_le_bytes())?;
+
+    Ok(CompressionStats {
+        table_bytes: (solved.len() + values.len()) as u64 * 8,
+        compressed_bytes: (offsets.len() * 8 + blocks.len()) as u64,
+    })
+}
+
+pub(super) fn read_store<P, RV>(
+    variant: P::Variant,
+    reader: impl Read,
+) -> Result<ArrayStore<P, RV>, DatabaseError>
+where
+    P: DatabasePosition,
+    RV: PackedValue,
+{
+    let mut reader = Fnv1aReader::new(reader);
+
+    let header = DatabaseHeader::read(&mut reader)?;
+    header.check::<P, RV>(&variant)?;
+
+    let layout = BlockLayout::new(&header);
+    let offsets = layout.read_offsets(&mut reader)?;
+
+    let mut blocks = vec![0; *offsets.last().expect("offsets should not be empty") as usize];
+    reader.read_exact(&mut blocks)?;
+
+    let checksum = reader.hash;
+
+    if read_u64(&mut reader.inner)? != checksum {
+        return Err(DatabaseError::ChecksumMismatch);
+    }
+
+    if reader.inner.read(&mut [0])? != 0 {
+        return Err(DatabaseError::Corrupt("trailing bytes after checksum"));
+    }
+
+    let (solved_words, value_words) = ArrayStore::<P, RV>::word_counts(&variant);
+    let mut solved = Vec::with_capacity(solved_words);
+    let mut values = Vec::with_capacity(value_words);
+
+    for pair in offsets.windows(2) {
+        let (block_solved, block_values) =
+            layout.decode(&blocks[pair[0] as usize..pair[1] as usize])?;
+
+        solved.extend(block_solved);
+        values.extend(block_values);
+    }
+
+    solved.truncate(solved_words);
+    values.truncate(value_words);
+
+    let unused_bits = solved_words as u64 * 64 - header.index_count;
+
+    if unused_bits > 0
+        && solved
+            .last()
+            .is_some_and(|word| word >> (64 - unused_bits) != 0)
+    {
+        return Err(DatabaseError::Corrupt("solved bit set past the last index"));
+    }
+
+    let store = ArrayStore::from_words(variant, solved, values)
+        .ok_or(DatabaseError::Corrupt("table size does not match variant"))?;
+
+    if store.len() as u64 != header.len {
+        return Err(DatabaseError::Corrupt("solved count does not match header"));
+    }
+
+    Ok(store)
+}
+
+pub(super) fn read_u16(reader: &mut impl Read) -> Result<u16, DatabaseError> {
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32, DatabaseError> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub(super) fn read_u64(reader: &mut impl Read) -> Result<u64, DatabaseError> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_string(reader: &mut impl Read) -> Result<String, DatabaseError> {
+    let mut bytes = vec![0; read_u16(reader)? as usize];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|_| DatabaseError::Corrupt("string is not UTF-8"))
+}
+
+fn write_string(writer: &mut impl Write, string: &str) -> io::Result<()> {
+    writer.write_all(&(string.len() as u16).to_le_bytes())?;
+    writer.write_all(string.as_bytes())
+}
+
+pub(super) const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+pub(super) fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Hashes everything written through it.
+struct Fnv1aWriter<W> {
+    inner: W,
+    hash: u64,
+}
+
+impl<W> Fnv1aWriter<W> {
+    fn new(inner: W) -> Self {
+        Fnv1aWriter {
+            inner,
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl<W: Write> Write for Fnv1aWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hash = fnv1a(self.hash, &buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Hashes everything read through it.
+struct Fnv1aReader<R> {
+    inner: R,
+    hash: u64,
+}
+
+impl<R> Fnv1aReader<R> {
+    fn new(inner: R) -> Self {
+        Fnv1aReader {
+            inner,
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl<R: Read> Read for Fnv1aReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hash = fnv1a(self.hash, &buf[..read]);
+        Ok(read)
+    }
+}
@@ -0,0 +1,230 @@
+//! LZ77 coding of database blocks, in the style of LZ4.
+//!
+//! A block is a sequence of packets, each a token byte, literals, then a
+//! match copying earlier output. The token's high nibble is the literal
+//! count and its low nibble the match length minus `MIN_MATCH`; a nibble of
+//! 15 continues in following bytes, each added on, until one is below 255.
+//! After the literals comes the match offset as a `u16`, back from the end
+//! of the output, then any continued match length. The last packet stops
+//! after its literals.
+//!
+//! Runs of a value are matches at an offset of the value's width, so
+//! unreachable and uniformly valued stretches cost a few bytes each.
+
+use super::DatabaseError;
+
+const MIN_MATCH: usize = 4;
+const MAX_OFFSET: usize = u16::MAX as usize;
+const HASH_BITS: u32 = 12;
+
+pub(super) fn encode(input: &[u8], output: &mut Vec<u8>) {
+    // Last position + 1 of each hashed 4-byte prefix, 0 meaning none.
+    let mut table = vec![0; 1 << HASH_BITS];
+    let mut literals_start = 0;
+    let mut i = 0;
+
+    while i + MIN_MATCH <= input.len() {
+        let candidate = std::mem::replace(&mut table[hash(&input[i..])], i + 1);
+
+        let start = match candidate.checked_sub(1) {
+            Some(start)
+                if i - start <= MAX_OFFSET
+                    && input[start..start + MIN_MATCH] == input[i..i + MIN_MATCH] =>
+            {
+                start
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+
+        let len = MIN_MATCH
+            + input[i + MIN_MATCH..]
+                .iter()
+                .zip(&input[start + MIN_MATCH..])
+                .take_while(|(a, b)| a == b)
+                .count();
+
+        write_packet(&input[literals_start..i], Some((i - start, len)), output);
+
+        for j in i + 1..(i + len).min(input.len() + 1 - MIN_MATCH) {
+            table[hash(&input[j..])] = j + 1;
+        }
+
+        i += len;
+        literals_start = i;
+    }
+
+    write_packet(&input[literals_start..], None, output);
+}
+
+fn hash(bytes: &[u8]) -> usize {
+    let prefix = u32::from_le_bytes(bytes[..4].try_into().expect("prefix should be 4 bytes"));
+
+    (prefix.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+}
+
+fn write_packet(literals: &[u8], copy: Option<(usize, usize)>, output: &mut Vec<u8>) {
+    let match_len = copy.map_or(0, |(_, len)| len - MIN_MATCH);
+
+    output.push((literals.len().min(15) << 4 | match_len.min(15)) as u8);
+    write_length(literals.len(), output);
+    output.extend_from_slice(literals);
+
+    if let Some((offset, _)) = copy {
+        output.extend_from_slice(&(offset as u16).to_le_bytes());
+        write_length(match_len, output);
+    }
+}
+
+fn write_length(len: usize, output: &mut Vec<u8>) {
+    if len < 15 {
+        return;
+    }
+
+    let mut rest = len - 15;
+
+    while rest >= 255 {
+        output.push(255);
+        rest -= 255;
+    }
+
+    output.push(rest as u8);
+}
+
+/// Decodes exactly `len` bytes, rejecting input that decodes to anything
+/// else.
+pub(super) fn decode(input: &[u8], len: usize) -> Result<Vec<u8>, DatabaseError> {
+    let mut output = Vec::with_capacity(len);
+    let mut input = input;
+
+    loop {
+        let token = read_byte(&mut input)?;
+
+        let literals = read_length(&mut input, token >> 4)?;
+
+        if input.len() < literals {
+            return Err(DatabaseError::Corrupt("block ends inside a packet"));
+        }
+
+        if output.len() + literals > len {
+            return Err(DatabaseError::Corrupt("block decodes past its size"));
+        }
+
+        let (literal_bytes, rest) = input.split_at(literals);
+        output.extend_from_slice(literal_bytes);
+        input = rest;
+
+        if input.is_empty() {
+            break;
+        }
+
+        let offset = u16::from_le_bytes([read_byte(&mut input)?, read_byte(&mut input)?]) as usize;
+        let match_len = read_length(&mut input, token & 15)? + MIN_MATCH;
+
+        if offset == 0 || offset > output.len() {
+            return Err(DatabaseError::Corrupt("match starts before the block"));
+        }
+
+        if output.len() + match_len > len {
+            return Err(DatabaseError::Corrupt("block decodes past its size"));
+        }
+
+        // Byte by byte, since a match may overlap its own output.
+        let start = output.len() - offset;
+        (start..start + match_len).for_each(|j| output.push(output[j]));
+    }
+
+    if output.len() != len {
+        return Err(DatabaseError::Corrupt("block decodes short of its size"));
+    }
+
+    Ok(output)
+}
+
+fn read_byte(input: &mut &[u8]) -> Result<u8, DatabaseError> {
+    let (&byte, rest) = input
+        .split_first()
+        .ok_or(DatabaseError::Corrupt("block ends inside a packet"))?;
+    *input = rest;
+
+    Ok(byte)
+}
+
+fn read_length(input: &mut &[u8], nibble: u8) -> Result<usize, DatabaseError> {
+    let mut len = nibble as usize;
+
+    if nibble == 15 {
+        loop {
+            let byte = read_byte(input)?;
+            len += byte as usize;
+
+            if byte < 255 {
+                break;
+            }
+        }
+    }
+
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+    use crate::database::DatabaseError;
+
+    fn round_trip(input: &[u8]) -> usize {
+        let mut encoded = vec![];
+        encode(input, &mut encoded);
+
+        assert_eq!(decode(&encoded, input.len()).unwrap(), input);
+
+        encoded.len()
+    }
+
+    #[test]
+    fn round_trips() {
+        let mixed: Vec<u8> = (0..5000u32)
+            .map(|i| match i % 700 < 300 {
+                true => 7,
+                false => (i * 31 % 251) as u8,
+            })
+            .collect();
+
+        assert_eq!(round_trip(&[]), 1);
+        assert_eq!(round_trip(&[1, 2, 3]), 4);
+        assert!(round_trip(&mixed) < mixed.len());
+
+        round_trip(&mixed[..15]);
+        round_trip(&mixed[..300]);
+        round_trip(&mixed[..1000]);
+    }
+
+    #[test]
+    fn runs_of_wide_values() {
+        let values: Vec<u8> = [5u8, 64].repeat(1000);
+
+        assert!(round_trip(&values) < 16);
+        assert!(round_trip(&[0; 10_000]) < 64);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let mut encoded = vec![];
+        encode(&[3; 100], &mut encoded);
+
+        assert!(decode(&encoded, 100).is_ok());
+
+        for (input, len) in [
+            (&encoded[..], 99),
+            (&encoded[..], 101),
+            (&encoded[..encoded.len() - 1], 100),
+            (&[], 0),
+            (&[0x10, 1, 0, 0], 5),
+            (&[0x10, 1, 2, 0], 5),
+        ] {
+            assert!(matches!(decode(input, len), Err(DatabaseError::Corrupt(_))));
+        }
+    }
+}
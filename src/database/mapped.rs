@@ -0,0 +1,433 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use super::format::{fnv1a, BlockLayout, FNV_OFFSET_BASIS};
+use super::mmap::Mmap;
+use super::{CompressionStats, DatabaseError, DatabaseHeader, DatabasePosition};
+use crate::solver::{ArrayStore, MemoStore, PackedValue, PositionKey};
+
+/// A block number with its decompressed solved words and value words.
+type CachedBlock = (usize, Vec<u64>, Vec<u64>);
+
+/// A database file answering lookups straight from a read-only memory map,
+/// without loading the table.
+///
+/// As a `MemoStore`, it lets `Solver::new` serve `best_moves`,
+/// `perfect_play` and friends from disk. Anything the file does not cover
+/// is solved as usual and kept in memory; the file is never written.
+///
+/// A lookup decompresses the block holding its position, checking it as it
+/// goes, and keeps it until a lookup lands in another block. A block that
+/// fails its check holds nothing as far as `MemoStore` is concerned, so its
+/// positions are solved again; `try_get` reports the error instead.
+#[derive(Debug)]
+pub struct MappedDatabase<P: DatabasePosition, RV> {
+    mmap: Mmap,
+    header: DatabaseHeader,
+    variant: P::Variant,
+    layout: BlockLayout,
+    blocks_offset: usize,
+    block_offsets: Vec<u64>,
+    last_block: RefCell<Option<CachedBlock>>,
+    overlay: HashMap<P, RV>,
+}
+
+impl<P, RV> MappedDatabase<P, RV>
+where
+    P: DatabasePosition,
+    RV: PackedValue,
+{
+    /// Checks the header, the block offsets and the file size. Blocks are
+    /// only checked as lookups reach them; see `check_blocks` and
+    /// `verify_checksum` for a full pass.
+    ///
+    /// # Safety
+    ///
+    /// The file is memory-mapped, so nothing may modify or truncate it while
+    /// the returned database is alive. Otherwise lookups may read changed
+    /// bytes or fault.
+    pub unsafe fn open(variant: P::Variant, path: impl AsRef<Path>) -> Result<Self, DatabaseError> {
+        // SAFETY: the caller keeps the file unchanged while it is mapped.
+        let mmap = unsafe { Mmap::open(&File::open(path)?)? };
+        let bytes = mmap.as_bytes();
+
+        let mut rest = bytes;
+        let header = DatabaseHeader::read(&mut rest)?;
+        header.check::<P, RV>(&variant)?;
+
+        let layout = BlockLayout::new(&header);
+        let block_offsets = layout.read_offsets(&mut rest)?;
+
+        let blocks_offset = bytes.len() - rest.len();
+        let checksum_offset = block_offsets
+            .last()
+            .and_then(|&end| usize::try_from(end).ok())
+            .and_then(|end| end.checked_add(blocks_offset))
+            .ok_or(DatabaseError::Truncated)?;
+
+        if bytes.len() < checksum_offset + 8 {
+            return Err(DatabaseError::Truncated);
+        }
+
+        if bytes.len() > checksum_offset + 8 {
+            return Err(DatabaseError::Corrupt("trailing bytes after checksum"));
+        }
+
+        Ok(MappedDatabase {
+            mmap,
+            header,
+            variant,
+            layout,
+            blocks_offset,
+            block_offsets,
+            last_block: RefCell::new(None),
+            overlay: HashMap::new(),
+        })
+    }
+
+    pub fn header(&self) -> &DatabaseHeader {
+        &self.header
+    }
+
+    pub fn compression_stats(&self) -> CompressionStats {
+        let (solved_words, value_words) = ArrayStore::<P, RV>::word_counts(&self.variant);
+        let blocks_end = self
+            .block_offsets
+            .last()
+            .expect("offsets should not be empty");
+
+        CompressionStats {
+            table_bytes: (solved_words + value_words) as u64 * 8,
+            compressed_bytes: self.block_offsets.len() as u64 * 8 + blocks_end,
+        }
+    }
+
+    pub fn verify_checksum(&self) -> Result<(), DatabaseError> {
+        let bytes = self.mmap.as_bytes();
+        let (data, checksum) = bytes.split_at(bytes.len() - 8);
+
+        match fnv1a(FNV_OFFSET_BASIS, data).to_le_bytes() == checksum {
+            true => Ok(()),
+            false => Err(DatabaseError::ChecksumMismatch),
+        }
+    }
+
+    /// Decompresses every block once and checks that their solved bits add
+    /// up to the header's count. Lookups never need this.
+    pub fn check_blocks(&self) -> Result<(), DatabaseError> {
+        let mut len = 0;
+
+        for block in 0..self.layout.block_count {
+            let (solved, _) = self.decode_block(block)?;
+
+            len += solved
+                .iter()
+                .map(|word| word.count_ones() as u64)
+                .sum::<u64>();
+        }
+
+        if len != self.header.len {
+            return Err(DatabaseError::Corrupt("solved count does not match header"));
+        }
+
+        Ok(())
+    }
+
+    /// Decompresses `block`, checking that no solved bit lies past the last
+    /// index.
+    fn decode_block(&self, block: usize) -> Result<(Vec<u64>, Vec<u64>), DatabaseError> {
+        let start = self.blocks_offset + self.block_offsets[block] as usize;
+        let end = self.blocks_offset + self.block_offsets[block + 1] as usize;
+
+        let (solved, values) = self.layout.decode(&self.mmap.as_bytes()[start..end])?;
+
+        let index_count = self.header.index_count as usize;
+        let first_index = block * self.layout.block_indices;
+
+        for (word_index, &word) in solved.iter().enumerate() {
+            let indices_left = index_count.saturating_sub(first_index + word_index * 64);
+            let past_the_end = u64::MAX.checked_shl(indices_left as u32).unwrap_or(0);
+
+            if word & past_the_end != 0 {
+                return Err(DatabaseError::Corrupt("solved bit set past the last index"));
+            }
+        }
+
+        Ok((solved, values))
+    }
+
+    fn get_index(&self, index: usize) -> Result<Option<RV>, DatabaseError> {
+        let block = index / self.layout.block_indices;
+        let mut last_block = self.last_block.borrow_mut();
+
+        if last_block
+            .as_ref()
+            .is_none_or(|(cached, ..)| *cached != block)
+        {
+            let (solved, values) = self.decode_block(block)?;
+            *last_block = Some((block, solved, values));
+        }
+
+        let (_, solved, values) = last_block.as_ref().expect("block should be cached");
+
+        Ok(Self::value_in_block(
+            solved,
+            values,
+            index % self.layout.block_indices,
+        ))
+    }
+
+    /// The value at `index` within a decompressed block, if it is solved.
+    fn value_in_block(solved: &[u64], values: &[u64], index: usize) -> Option<RV> {
+        if solved[index / 64] >> (index % 64) & 1 == 0 {
+            return None;
+        }
+
+        let (word, offset) = ArrayStore::<P, RV>::value_slot(index);
+        let mask = u64::MAX >> (64 - RV::BITS);
+
+        Some(RV::unpack(values[word] >> offset & mask))
+    }
+}
+
+impl<P, RV> MappedDatabase<P, RV>
+where
+    P: DatabasePosition + PositionKey,
+    RV: PackedValue + Clone,
+{
+    /// Same as `MemoStore::get`, but fails if the position's block is
+    /// corrupt.
+    pub fn try_get(&self, position: &P) -> Result<Option<RV>, DatabaseError> {
+        match self.get_index(position.to_index() as usize)? {
+            Some(value) => Ok(Some(value)),
+            None => Ok(self.overlay.get(position).cloned()),
+        }
+    }
+}
+
+impl<P, RV> MemoStore<P, RV> for MappedDatabase<P, RV>
+where
+    P: DatabasePosition + PositionKey,
+    RV: PackedValue + Clone,
+{
+    fn get(&self, position: &P) -> Option<RV> {
+        self.get_index(position.to_index() as usize)
+            .ok()
+            .flatten()
+            .or_else(|| self.overlay.get(position).cloned())
+    }
+
+    fn insert(&mut self, position: P, value: RV) {
+        self.overlay.insert(position, value);
+    }
+
+    fn contains(&self, position: &P) -> bool {
+        self.get(position).is_some()
+    }
+
+    fn len(&self) -> usize {
+        self.header.len as usize + self.overlay.len()
+    }
+
+    /// Decompresses every block once, in order, bypassing the cached block.
+    /// Corrupt blocks are skipped.
+    fn for_each(&self, mut visit: impl FnMut(&P, &RV)) {
+        for block in 0..self.layout.block_count {
+            let Ok((solved, values)) = self.decode_block(block) else {
+                continue;
+            };
+            let first_index = block * self.layout.block_indices;
+
+            for (word_index, &word) in solved.iter().enumerate() {
+                let mut word = word;
+
+                while word != 0 {
+                    let index = word_index * 64 + word.trailing_zeros() as usize;
+                    word &= word - 1;
+
+                    let position = P::from_index(&self.variant, (first_index + index) as u64);
+                    let value = Self::value_in_block(&solved, &values, index)
+                        .expect("solved bit should be set");
+                    visit(&position, &value);
+                }
+            }
+        }
+
+        self.overlay
+            .iter()
+            .for_each(|(position, value)| visit(position, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use super::MappedDatabase;
+    use crate::database::{DatabaseError, DatabasePosition};
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::solver::{
+        GameResult, GameResultWithRmt, MemoStore, PackedValue, Position, PositionHash, Solver,
+    };
+
+    fn open<P, RV>(path: &Path) -> Result<MappedDatabase<P, RV>, DatabaseError>
+    where
+        P: DatabasePosition<Variant = ()>,
+        RV: PackedValue,
+    {
+        // SAFETY: each test has a file of its own, which it only rewrites
+        // while nothing maps it.
+        unsafe { MappedDatabase::open((), path) }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("gamescrafters-{name}-{}.db", std::process::id()))
+    }
+
+    #[test]
+    fn same_as_in_memory() {
+        let path = temp_path("mapped-same-as-in-memory");
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        solver.solve(TicTacToePosition::start());
+        let stats = solver.save_database((), &path).unwrap();
+
+        let mapped = open::<TicTacToePosition, GameResultWithRmt>(&path);
+        fs::remove_file(&path).unwrap();
+
+        let mapped = mapped.unwrap();
+        mapped.verify_checksum().unwrap();
+        assert_eq!(mapped.compression_stats(), stats);
+
+        let mut mapped_solver = Solver::<_, _, _, GameResultWithRmt, _>::new(mapped);
+
+        assert_eq!(mapped_solver.len(), solver.len());
+        assert_eq!(
+            mapped_solver.best_moves(&TicTacToePosition::start()),
+            solver.best_moves(&TicTacToePosition::start())
+        );
+        assert_eq!(
+            mapped_solver.perfect_play(&TicTacToePosition::start()),
+            solver.perfect_play(&TicTacToePosition::start())
+        );
+
+        for (position, result) in &solver {
+            assert_eq!(mapped_solver.solved_value(position).as_ref(), Some(result));
+        }
+
+        // Everything was answered from the file.
+        assert_eq!(mapped_solver.len(), solver.len());
+    }
+
+    #[test]
+    fn solves_what_the_file_lacks() {
+        let path = temp_path("mapped-solves-what-the-file-lacks");
+        let later = TicTacToePositionD4Eq::new(TicTacToePosition::start())
+            .do_move(TicTacToePosition::start().generate_moves()[4].clone());
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        solver.solve(later.clone());
+        solver.save_database((), &path).unwrap();
+
+        let mapped = open::<TicTacToePositionD4Eq, GameResult>(&path);
+        fs::remove_file(&path).unwrap();
+
+        let mut mapped_solver = Solver::<_, _, _, GameResult, _>::new(mapped.unwrap());
+        let mut full = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(
+            mapped_solver.solve(TicTacToePositionD4Eq::new(TicTacToePosition::start())),
+            full.solve(TicTacToePositionD4Eq::new(TicTacToePosition::start()))
+        );
+        assert_eq!(mapped_solver.len(), full.len());
+    }
+
+    #[test]
+    fn corrupt_block() {
+        let path = temp_path("mapped-corrupt-block");
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        solver.solve(TicTacToePosition::start());
+        solver.save_database((), &path).unwrap();
+
+        let mapped = open::<TicTacToePosition, GameResult>(&path).unwrap();
+        let first_block =
+            mapped.blocks_offset..mapped.blocks_offset + mapped.block_offsets[1] as usize;
+        drop(mapped);
+
+        // A zero token followed by a zero offset is a match before the block.
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[first_block].fill(0);
+        fs::write(&path, bytes).unwrap();
+
+        let mapped = open::<TicTacToePosition, GameResult>(&path);
+        fs::remove_file(&path).unwrap();
+
+        let mapped = mapped.unwrap();
+        let first = TicTacToePosition::from_index(&(), 0);
+
+        assert!(matches!(
+            mapped.try_get(&first),
+            Err(DatabaseError::Corrupt(_))
+        ));
+        assert!(matches!(
+            mapped.check_blocks(),
+            Err(DatabaseError::Corrupt(_))
+        ));
+        assert_eq!(mapped.get(&first), None);
+
+        // Positions in the corrupt block are solved again.
+        let mut mapped_solver = Solver::<_, _, _, GameResult, _>::new(mapped);
+
+        assert_eq!(
+            mapped_solver.solve(TicTacToePosition::start()),
+            solver.solve(TicTacToePosition::start())
+        );
+    }
+
+    #[test]
+    fn open_errors() {
+        let path = temp_path("mapped-open-errors");
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        solver.solve(TicTacToePosition::start());
+        solver.save_database((), &path).unwrap();
+
+        assert!(matches!(
+            open::<TicTacToePosition, GameResultWithRmt>(&path),
+            Err(DatabaseError::ValueTypeMismatch { .. })
+        ));
+
+        let bytes = fs::read(&path).unwrap();
+        fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+        assert!(matches!(
+            open::<TicTacToePosition, GameResult>(&path),
+            Err(DatabaseError::Truncated)
+        ));
+
+        fs::write(&path, []).unwrap();
+
+        assert!(matches!(
+            open::<TicTacToePosition, GameResult>(&path),
+            Err(DatabaseError::Truncated)
+        ));
+
+        let mut flipped = bytes;
+        let last_value = flipped.len() - 9;
+        flipped[last_value] ^= 1;
+        fs::write(&path, flipped).unwrap();
+
+        let mapped = open::<TicTacToePosition, GameResult>(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            mapped.unwrap().verify_checksum(),
+            Err(DatabaseError::ChecksumMismatch)
+        ));
+    }
+}
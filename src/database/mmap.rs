@@ -0,0 +1,136 @@
+use std::fs::File;
+use std::io;
+
+/// The whole contents of a file, memory-mapped read-only where supported
+/// and read into memory otherwise.
+pub(super) struct Mmap {
+    #[cfg(all(unix, target_pointer_width = "64"))]
+    ptr: *const u8,
+    #[cfg(all(unix, target_pointer_width = "64"))]
+    len: usize,
+
+    #[cfg(not(all(unix, target_pointer_width = "64")))]
+    bytes: Vec<u8>,
+}
+
+#[cfg(all(unix, target_pointer_width = "64"))]
+mod sys {
+    use std::ffi::{c_int, c_void};
+
+    pub const PROT_READ: c_int = 1;
+    pub const MAP_PRIVATE: c_int = 2;
+
+    extern "C" {
+        pub fn mmap(
+            addr: *mut c_void,
+            len: usize,
+            prot: c_int,
+            flags: c_int,
+            fd: c_int,
+            offset: i64,
+        ) -> *mut c_void;
+
+        pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    }
+}
+
+#[cfg(all(unix, target_pointer_width = "64"))]
+impl Mmap {
+    /// # Safety
+    ///
+    /// Nothing may modify or truncate the file while the returned value is
+    /// alive. The mapping reads the file's pages as they are, so a change
+    /// would alter bytes already handed out as `&[u8]`, and a truncation
+    /// would make reading them fault.
+    pub(super) unsafe fn open(file: &File) -> io::Result<Mmap> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "file too large to map"))?;
+
+        // `mmap` rejects empty mappings.
+        if len == 0 {
+            return Ok(Mmap {
+                ptr: std::ptr::NonNull::dangling().as_ptr(),
+                len,
+            });
+        }
+
+        // SAFETY: a fresh private read-only mapping of an open file; the
+        // result is checked against `MAP_FAILED` before use. The caller
+        // keeps the file unchanged while it is mapped.
+        let ptr = unsafe {
+            sys::mmap(
+                std::ptr::null_mut(),
+                len,
+                sys::PROT_READ,
+                sys::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+
+        if ptr as isize == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Mmap {
+            ptr: ptr as *const u8,
+            len,
+        })
+    }
+
+    pub(super) fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `ptr` points to `len` mapped bytes that live until `drop`,
+        // and `open`'s caller keeps the file from changing under them.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+#[cfg(all(unix, target_pointer_width = "64"))]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            // SAFETY: unmaps exactly the mapping made in `open`.
+            unsafe {
+                sys::munmap(self.ptr as *mut _, self.len);
+            }
+        }
+    }
+}
+
+// SAFETY: the mapping is read-only and owned by this value alone.
+#[cfg(all(unix, target_pointer_width = "64"))]
+unsafe impl Send for Mmap {}
+
+// SAFETY: the mapping is read-only and owned by this value alone.
+#[cfg(all(unix, target_pointer_width = "64"))]
+unsafe impl Sync for Mmap {}
+
+#[cfg(not(all(unix, target_pointer_width = "64")))]
+impl Mmap {
+    /// # Safety
+    ///
+    /// Always safe here, since the file is read into memory; `unsafe` only
+    /// to match the memory-mapped version.
+    pub(super) unsafe fn open(file: &File) -> io::Result<Mmap> {
+        use std::io::Read;
+
+        let mut bytes = vec![];
+        (&*file).read_to_end(&mut bytes)?;
+
+        Ok(Mmap { bytes })
+    }
+
+    pub(super) fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl std::fmt::Debug for Mmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mmap")
+            .field("len", &self.as_bytes().len())
+            .finish()
+    }
+}
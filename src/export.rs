@@ -0,0 +1,305 @@
+//! Every solved position as CSV or JSON, for spreadsheets and notebooks.
+//!
+//! Each row holds:
+//!
+//! | Column       | Contents                                             |
+//! |--------------|------------------------------------------------------|
+//! | `board`      | [`ExportPosition::board_string`]                     |
+//! | `player`     | [`ExportPosition::player_to_move`], empty if `None`  |
+//! | `result`     | `Win`, `Lose`, `Tie` or `Draw`                       |
+//! | `remoteness` | a number, `inf`, or empty if the value has none      |
+//! | `primitive`  | `true` or `false`                                    |
+//!
+//! In JSON, each row is an object with the same keys, and a missing player
+//! or remoteness is `null`. Rows are written straight from the memo table
+//! as it is walked, in no particular order.
+
+use std::io::{self, Write};
+
+use crate::solver::{
+    GameResult, MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue,
+    RmtU32, Solver, ToGameResult, ToRecursiveValue, ToRmt,
+};
+
+/// Positions that can be described in an export.
+pub trait ExportPosition {
+    /// The position on one line, e.g. `"X-O/-X-/--O"` for a board, top row
+    /// first.
+    fn board_string(&self) -> String;
+
+    /// `None` for impartial games, whose positions do not record whose turn
+    /// it is.
+    fn player_to_move(&self) -> Option<String>;
+}
+
+struct Row {
+    board: String,
+    player: Option<String>,
+    result: GameResult,
+    rmt: Option<RmtU32>,
+    primitive: bool,
+}
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey + ExportPosition,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue + ToGameResult + ToRmt,
+    S: MemoStore<P, RV>,
+{
+    /// A header line, then one line per solved position.
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "board,player,result,remoteness,primitive")?;
+
+        self.try_for_each_row(|row| {
+            let rmt = match row.rmt {
+                Some(RmtU32::Val(v)) => v.to_string(),
+                Some(RmtU32::Inf) => "inf".to_string(),
+                None => String::new(),
+            };
+
+            writeln!(
+                writer,
+                "{},{},{:?},{},{}",
+                csv_field(&row.board),
+                csv_field(row.player.as_deref().unwrap_or_default()),
+                row.result,
+                rmt,
+                row.primitive
+            )
+        })
+    }
+
+    /// An array of one object per solved position, one per line.
+    pub fn write_json(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "[")?;
+
+        let mut separator = "";
+
+        self.try_for_each_row(|row| {
+            let rmt = match row.rmt {
+                Some(RmtU32::Val(v)) => v.to_string(),
+                Some(RmtU32::Inf) => "\"inf\"".to_string(),
+                None => "null".to_string(),
+            };
+
+            write!(
+                writer,
+                "{}\n{{\"board\":{},\"player\":{},\"result\":\"{:?}\",\"remoteness\":{},\"primitive\":{}}}",
+                separator,
+                json_string(&row.board),
+                row.player.as_deref().map_or("null".to_string(), json_string),
+                row.result,
+                rmt,
+                row.primitive
+            )?;
+
+            separator = ",";
+
+            Ok(())
+        })?;
+
+        writeln!(writer, "\n]")
+    }
+
+    /// Stops visiting after the first error, which is returned.
+    fn try_for_each_row(&self, mut visit: impl FnMut(Row) -> io::Result<()>) -> io::Result<()> {
+        let mut result = Ok(());
+
+        self.for_each_solved(|position, value| {
+            if result.is_err() {
+                return;
+            }
+
+            result = visit(Row {
+                board: position.board_string(),
+                player: position.player_to_move(),
+                result: value.to_game_result(),
+                rmt: value.to_rmt(),
+                primitive: position.primitive_value().is_primitive(),
+            });
+        });
+
+        result
+    }
+}
+
+/// Quotes `field` if it would otherwise break the row.
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+fn json_string(string: &str) -> String {
+    let mut json = String::with_capacity(string.len() + 2);
+
+    json.push('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{csv_field, ExportPosition};
+    use crate::games::connect_four::ConnectFourPosition;
+    use crate::games::disjunctive_sum::DisjunctiveSum;
+    use crate::games::misere::Misere;
+    use crate::games::mnk::MnkPosition;
+    use crate::games::nim::{NimPosition, NimPositionPermEq};
+    use crate::games::order_and_chaos::OrderAndChaosGame;
+    use crate::games::subtraction::SubtractionGame;
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::tic_tac_toe_non_sq::{
+        TicTacToeNonSqPlayer, TicTacToeNonSqPosition, TicTacToeNonSqPositionVEq,
+    };
+    use crate::solver::{
+        GameResult, GameResultWithRmt, PlayerMove, Position, PositionKey, PrimitiveValue,
+        RecursiveValue, Solver, ToGameResult, ToRecursiveValue, ToRmt,
+    };
+
+    /// The CSV rows after the header, sorted.
+    fn csv_rows<P, M, PV, RV>(start: P) -> Vec<String>
+    where
+        P: Position<M, PV> + PositionKey + ExportPosition,
+        M: PlayerMove,
+        PV: PrimitiveValue + ToRecursiveValue<RV>,
+        RV: RecursiveValue + ToGameResult + ToRmt,
+    {
+        let mut solver = Solver::<_, _, _, RV>::new(HashMap::new());
+        solver.solve(start);
+
+        let mut csv = vec![];
+        solver.write_csv(&mut csv).unwrap();
+
+        let mut lines: Vec<_> = String::from_utf8(csv)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        assert_eq!(lines.remove(0), "board,player,result,remoteness,primitive");
+        assert_eq!(lines.len(), solver.len());
+
+        lines.sort();
+        lines
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            csv_rows::<_, _, _, GameResultWithRmt>(take_10_to_0::position(4)),
+            [
+                "0 (1/2),,Lose,0,true",
+                "1 (1/2),,Win,1,false",
+                "2 (1/2),,Win,1,false",
+                "3 (1/2),,Lose,2,false",
+                "4 (1/2),,Win,3,false",
+            ]
+        );
+
+        let rows = csv_rows::<_, _, _, GameResult>(TicTacToePosition::start());
+
+        assert_eq!(rows.len(), 5478);
+        assert!(rows.contains(&"---/---/---,X,Tie,,false".to_string()));
+        assert!(rows.contains(&"XXX/OO-/---,O,Lose,,true".to_string()));
+
+        assert_eq!(csv_field("X-O"), "X-O");
+        assert_eq!(csv_field("3,\"4\""), "\"3,\"\"4\"\"\"");
+    }
+
+    #[test]
+    fn json() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let mut json = vec![];
+        solver.write_json(&mut json).unwrap();
+        assert_eq!(String::from_utf8(json).unwrap(), "[\n]\n");
+
+        solver.solve(take_10_to_0::position(1));
+
+        let mut json = vec![];
+        solver.write_json(&mut json).unwrap();
+
+        let json = String::from_utf8(json).unwrap();
+        let mut rows: Vec<_> = json
+            .lines()
+            .map(|line| line.trim_end_matches(','))
+            .collect();
+        rows[1..3].sort();
+
+        assert_eq!(json.matches("},\n").count(), 1);
+
+        assert_eq!(
+            rows,
+            [
+                "[",
+                "{\"board\":\"0 (1/2)\",\"player\":null,\"result\":\"Lose\",\"remoteness\":0,\"primitive\":true}",
+                "{\"board\":\"1 (1/2)\",\"player\":null,\"result\":\"Win\",\"remoteness\":1,\"primitive\":false}",
+                "]",
+            ]
+        );
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        solver.solve(TicTacToePositionD4Eq::new(TicTacToePosition::start()));
+
+        let mut json = vec![];
+        solver.write_json(&mut json).unwrap();
+
+        let json = String::from_utf8(json).unwrap();
+
+        assert_eq!(json.lines().count(), solver.len() + 2);
+        assert!(json.contains(
+            "{\"board\":\"---/---/---\",\"player\":\"X\",\"result\":\"Tie\",\"remoteness\":null,\"primitive\":false}"
+        ));
+    }
+
+    #[test]
+    fn every_game() {
+        let tic_tac_toe_non_sq = TicTacToeNonSqPosition {
+            board: [[None; 4]; 3],
+            player: TicTacToeNonSqPlayer::X,
+        };
+        let subtraction = SubtractionGame::new(&[1, 3]);
+
+        csv_rows::<_, _, _, GameResultWithRmt>(TicTacToeNonSqPositionVEq::new(tic_tac_toe_non_sq));
+        csv_rows::<_, _, _, GameResultWithRmt>(MnkPosition::start(3, 2, 2));
+        csv_rows::<_, _, _, GameResultWithRmt>(
+            OrderAndChaosGame::new(3, 2, 2).start_color_sym_eq(),
+        );
+        csv_rows::<_, _, _, GameResultWithRmt>(NimPositionPermEq::new(NimPosition {
+            heaps: vec![2, 3],
+        }));
+
+        let connect_four =
+            csv_rows::<_, _, _, GameResultWithRmt>(ConnectFourPosition::start(2, 2, 2));
+        assert!(connect_four.contains(&"--/XO,X,Win,1,false".to_string()));
+        assert!(connect_four.contains(&"-O/XX,O,Lose,0,true".to_string()));
+
+        assert!(csv_rows::<_, _, _, GameResultWithRmt>(Misere {
+            position: subtraction.position(2)
+        })
+        .contains(&"0 (1/3),,Win,0,true".to_string()));
+        assert!(csv_rows::<_, _, _, GameResult>(DisjunctiveSum {
+            components: vec![subtraction.position(2), subtraction.position(1)],
+        })
+        .contains(&"2 (1/3) + 1 (1/3),,Win,,false".to_string()));
+    }
+}
@@ -1,4 +1,12 @@
+pub mod board;
+pub mod connect_four;
+pub mod disjunctive_sum;
+pub mod misere;
+pub mod mnk;
+pub mod nim;
 pub mod order_and_chaos;
+pub mod subtraction;
+pub mod symmetric;
 pub mod take_10_to_0;
 pub mod take_25_to_0;
 pub mod tic_tac_toe;
@@ -0,0 +1,236 @@
+//! Helpers shared by the runtime-sized board games' rules, symmetries,
+//! [`PositionHash`] and [`ExportPosition`] implementations. Boards are
+//! `board[row][column]`.
+//!
+//! [`PositionHash`]: crate::solver::PositionHash
+//! [`ExportPosition`]: crate::export::ExportPosition
+
+use std::fmt::Debug;
+
+/// Dimensions of a runtime-sized k-in-a-row board.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct BoardVariant {
+    pub width: usize,
+    pub height: usize,
+    pub k_in_a_row: usize,
+}
+
+impl BoardVariant {
+    /// `[width, height, k_in_a_row]`, as recorded in database headers.
+    pub fn to_params(&self) -> Vec<u64> {
+        vec![
+            self.width as u64,
+            self.height as u64,
+            self.k_in_a_row as u64,
+        ]
+    }
+}
+
+/// Whether `k` cells in a line along a row, a column or a diagonal all hold
+/// `piece`.
+pub fn has_k_in_a_row<C: PartialEq>(board: &[Vec<Option<C>>], k: usize, piece: &C) -> bool {
+    let k = k as i32;
+    let height = board.len() as i32;
+    let width = board[0].len() as i32;
+
+    let is_piece = |row: i32, column: i32| {
+        (0..height).contains(&row)
+            && (0..width).contains(&column)
+            && board[row as usize][column as usize].as_ref() == Some(piece)
+    };
+
+    [(0, 1), (1, 0), (1, 1), (1, -1)]
+        .iter()
+        .any(|&(d_row, d_column)| {
+            (0..height).any(|row| {
+                (0..width)
+                    .any(|column| (0..k).all(|i| is_piece(row + i * d_row, column + i * d_column)))
+            })
+        })
+}
+
+/// `board` with the order of its rows reversed.
+pub fn reflect_along_x<C: Clone>(board: &[Vec<C>]) -> Vec<Vec<C>> {
+    board.iter().rev().cloned().collect()
+}
+
+/// `board` with every row reversed.
+pub fn reflect_along_y<C: Clone>(board: &[Vec<C>]) -> Vec<Vec<C>> {
+    board
+        .iter()
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect()
+}
+
+/// `board` with its rows and columns swapped.
+pub fn transpose<C: Clone>(board: &[Vec<C>]) -> Vec<Vec<C>> {
+    (0..board[0].len())
+        .map(|column| board.iter().map(|row| row[column].clone()).collect())
+        .collect()
+}
+
+/// `3^cells`: every cell is empty or one of two pieces. The players take
+/// turns filling cells, so the player to move follows from the board and
+/// takes no room in the index.
+pub fn base_3_index_count(cells: usize) -> u64 {
+    3u64.checked_pow(cells as u32)
+        .expect("Board should be small enough to index with a u64")
+}
+
+/// Ranks `digits` (each in `0..3`, first digit least significant) into a
+/// single index.
+pub fn rank_base_3(digits: impl IntoIterator<Item = u64>) -> u64 {
+    digits
+        .into_iter()
+        .fold((0, 1), |(rank, place), digit| {
+            (rank + digit * place, place * 3)
+        })
+        .0
+}
+
+/// The inverse of [`rank_base_3`] for a board of `cells` cells, along with
+/// whether an odd number of cells are filled, i.e. whether the second
+/// player is to move.
+pub fn unrank_base_3(mut index: u64, cells: usize) -> (Vec<u64>, bool) {
+    let digits = (0..cells)
+        .map(|_| {
+            let digit = index % 3;
+            index /= 3;
+            digit
+        })
+        .collect::<Vec<_>>();
+
+    let second_player = digits.iter().filter(|&&digit| digit != 0).count() % 2 == 1;
+
+    (digits, second_player)
+}
+
+/// `rows` joined by `/`, with each piece as its `Debug` name and `-` for an
+/// empty cell, e.g. `"X-O/-X-/--O"`.
+pub fn board_string<'a, C: Debug + 'a>(rows: impl IntoIterator<Item = &'a [Option<C>]>) -> String {
+    rows.into_iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    Some(piece) => format!("{piece:?}"),
+                    None => "-".to_string(),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::fmt::Debug;
+
+    use super::{has_k_in_a_row, reflect_along_x, reflect_along_y, transpose};
+    use crate::games::connect_four::{ConnectFourPosition, ConnectFourPositionMirrorEq};
+    use crate::games::mnk::MnkPosition;
+    use crate::games::order_and_chaos::OrderAndChaosGame;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::tic_tac_toe_non_sq::{TicTacToeNonSqPlayer, TicTacToeNonSqPosition};
+    use crate::solver::{
+        GameResult, PlayerMove, Position, PositionHash, PositionKey, PrimitiveValue, Solver,
+        ToRecursiveValue,
+    };
+
+    /// Every reachable position round-trips and gets its own index.
+    fn assert_round_trip<P, M, PV>(start: P)
+    where
+        P: Position<M, PV> + PositionKey + PositionHash + Clone + Debug,
+        M: PlayerMove,
+        PV: PrimitiveValue + ToRecursiveValue<GameResult>,
+    {
+        let variant = start.variant();
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        solver.solve(start);
+
+        let mut indices = HashSet::new();
+
+        for (position, _) in &solver {
+            let index = position.to_index();
+
+            assert!(index < P::index_count(&variant));
+            assert_eq!(&P::from_index(&variant, index), position);
+            assert!(indices.insert(index));
+        }
+    }
+
+    #[test]
+    fn round_trip_tic_tac_toe() {
+        let start = TicTacToePosition::start();
+
+        assert_round_trip(start.clone());
+        assert_round_trip(TicTacToePositionD4Eq::new(start));
+    }
+
+    #[test]
+    fn round_trip_tic_tac_toe_non_sq() {
+        assert_round_trip(TicTacToeNonSqPosition {
+            board: [[None; 4]; 3],
+            player: TicTacToeNonSqPlayer::X,
+        });
+    }
+
+    #[test]
+    fn round_trip_mnk() {
+        assert_round_trip(MnkPosition::start(3, 2, 2));
+        assert_round_trip(MnkPosition::start(2, 4, 3));
+    }
+
+    #[test]
+    fn round_trip_order_and_chaos() {
+        assert_round_trip(OrderAndChaosGame::new(3, 2, 2).start());
+        assert_round_trip(OrderAndChaosGame::new(3, 3, 3).start_color_sym_eq());
+    }
+
+    #[test]
+    fn round_trip_connect_four() {
+        assert_round_trip(ConnectFourPosition::start(4, 3, 3));
+        assert_round_trip(ConnectFourPositionMirrorEq::new(
+            ConnectFourPosition::start(4, 3, 3),
+        ));
+    }
+
+    #[test]
+    fn k_in_a_row_lines() {
+        let board = |rows: [[u8; 3]; 2]| {
+            rows.iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&cell| (cell != 0).then_some(cell))
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>()
+        };
+
+        assert!(has_k_in_a_row(&board([[1, 1, 0], [0, 0, 0]]), 2, &1));
+        assert!(has_k_in_a_row(&board([[0, 1, 0], [0, 1, 0]]), 2, &1));
+        assert!(has_k_in_a_row(&board([[1, 0, 0], [0, 1, 0]]), 2, &1));
+        assert!(has_k_in_a_row(&board([[0, 0, 1], [0, 1, 0]]), 2, &1));
+        assert!(!has_k_in_a_row(&board([[1, 0, 1], [0, 2, 0]]), 2, &1));
+        assert!(!has_k_in_a_row(&board([[1, 1, 0], [0, 0, 0]]), 3, &1));
+    }
+
+    #[test]
+    fn reflections_and_transpose() {
+        let board = vec![vec![1, 2, 3], vec![4, 5, 6]];
+
+        assert_eq!(reflect_along_x(&board), [[4, 5, 6], [1, 2, 3]]);
+        assert_eq!(reflect_along_y(&board), [[3, 2, 1], [6, 5, 4]]);
+        assert_eq!(transpose(&board), [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn index_count() {
+        assert_eq!(TicTacToePosition::index_count(&()), 19_683);
+        assert_eq!(
+            ConnectFourPosition::index_count(&ConnectFourPosition::start(7, 6, 4).variant()),
+            1 << 49
+        );
+    }
+}
@@ -0,0 +1,108 @@
+mod position;
+mod recursive_value;
+
+pub use self::position::ConnectFourPlayer;
+pub use self::position::ConnectFourPosition;
+pub use self::position::ConnectFourPositionMirrorEq;
+
+use crate::solver::{PlayerMove, PrimitiveValue};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConnectFourPrimitiveValue {
+    _Win,
+    Lose,
+    Tie,
+    NotPrimitive,
+}
+
+impl PrimitiveValue for ConnectFourPrimitiveValue {
+    fn is_primitive(&self) -> bool {
+        !matches!(self, ConnectFourPrimitiveValue::NotPrimitive)
+    }
+}
+
+/// Drops a piece into `column`, where it falls to the lowest empty cell.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConnectFourMove {
+    pub column: usize,
+}
+
+impl PlayerMove for ConnectFourMove {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{ConnectFourPosition, ConnectFourPositionMirrorEq};
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver};
+
+    #[test]
+    fn connect_3_on_3_by_3() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve(ConnectFourPosition::start(3, 3, 3));
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(9),
+            }
+        );
+    }
+
+    #[test]
+    fn mirror_eq() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut mirror_eq_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            solver.solve(ConnectFourPosition::start(4, 3, 3)),
+            mirror_eq_solver.solve(ConnectFourPositionMirrorEq::new(
+                ConnectFourPosition::start(4, 3, 3)
+            ))
+        );
+        assert!(mirror_eq_solver.len() < solver.len());
+    }
+
+    #[test]
+    fn connect_4_on_4_by_4() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve(ConnectFourPositionMirrorEq::new(
+            ConnectFourPosition::start(4, 4, 4),
+        ));
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(16),
+            }
+        );
+    }
+
+    /// About 100s in a debug build, so only run with
+    /// `--features slow-tests`, ideally with `--release` as well.
+    ///
+    /// 6x5 is out of reach: it has far too many positions for a `HashMap`,
+    /// and its 2^36 indices would take an `ArrayStore` over 100 GiB.
+    #[test]
+    #[cfg_attr(not(feature = "slow-tests"), ignore)]
+    fn connect_4_on_5_by_4() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve(ConnectFourPositionMirrorEq::new(
+            ConnectFourPosition::start(5, 4, 4),
+        ));
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(20),
+            }
+        );
+        assert_eq!(solver.len(), 1_974_174);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::export::ExportPosition;
+use crate::games::board::{board_string, has_k_in_a_row};
+use crate::solver::{Position, PositionKey, Tier};
+
+use super::{ConnectFourMove, ConnectFourPrimitiveValue};
+
+mod position_hash;
+mod position_mirror_eq;
+
+pub use position_mirror_eq::ConnectFourPositionMirrorEq;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum ConnectFourPlayer {
+    X,
+    O,
+}
+
+impl ConnectFourPlayer {
+    fn next_player(&self) -> ConnectFourPlayer {
+        match self {
+            ConnectFourPlayer::X => ConnectFourPlayer::O,
+            ConnectFourPlayer::O => ConnectFourPlayer::X,
+        }
+    }
+}
+
+/// `board[row][column]`, with row 0 at the bottom.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct ConnectFourPosition {
+    pub k_in_a_row: usize,
+    pub board: Vec<Vec<Option<ConnectFourPlayer>>>,
+    pub player: ConnectFourPlayer,
+}
+
+impl ConnectFourPosition {
+    pub fn start(width: usize, height: usize, k_in_a_row: usize) -> ConnectFourPosition {
+        assert!(width >= 1 && height >= 1, "Board should not be empty");
+        assert!(k_in_a_row >= 1, "K_IN_A_ROW should be at least 1");
+
+        ConnectFourPosition {
+            k_in_a_row,
+            board: vec![vec![None; width]; height],
+            player: ConnectFourPlayer::X,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.board[0].len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.board.len()
+    }
+}
+
+impl PositionKey for ConnectFourPosition {}
+
+/// Top row first.
+impl ExportPosition for ConnectFourPosition {
+    fn board_string(&self) -> String {
+        board_string(self.board.iter().rev().map(|row| &row[..]))
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        Some(format!("{:?}", self.player))
+    }
+}
+
+impl Tier for ConnectFourPosition {
+    fn tier(&self) -> u32 {
+        self.board
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_some())
+            .count() as u32
+    }
+}
+
+impl Position<ConnectFourMove, ConnectFourPrimitiveValue> for ConnectFourPosition {
+    fn do_move(&self, mov: ConnectFourMove) -> ConnectFourPosition {
+        let mut board = self.board.clone();
+
+        let row = board
+            .iter()
+            .position(|line| line[mov.column].is_none())
+            .expect("Column should not be full");
+
+        board[row][mov.column] = Some(self.player);
+
+        ConnectFourPosition {
+            k_in_a_row: self.k_in_a_row,
+            board,
+            player: self.player.next_player(),
+        }
+    }
+
+    fn generate_moves(&self) -> Vec<ConnectFourMove> {
+        let top = &self.board[self.height() - 1];
+
+        (0..self.width())
+            .filter(|&column| top[column].is_none())
+            .map(|column| ConnectFourMove { column })
+            .collect()
+    }
+
+    fn primitive_value(&self) -> ConnectFourPrimitiveValue {
+        if has_k_in_a_row(&self.board, self.k_in_a_row, &self.player.next_player()) {
+            return ConnectFourPrimitiveValue::Lose;
+        }
+
+        if self.board[self.height() - 1]
+            .iter()
+            .all(|cell| cell.is_some())
+        {
+            return ConnectFourPrimitiveValue::Tie;
+        }
+
+        ConnectFourPrimitiveValue::NotPrimitive
+    }
+}
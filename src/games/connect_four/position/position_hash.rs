@@ -0,0 +1,95 @@
+use crate::database::DatabasePosition;
+use crate::games::board::BoardVariant;
+use crate::solver::PositionHash;
+
+use super::{ConnectFourPlayer, ConnectFourPosition};
+
+/// Each column takes `height + 1` bits: one bit per piece (set for O),
+/// topped by a sentinel bit marking how full the column is. The player to
+/// move follows from the number of pieces.
+impl PositionHash for ConnectFourPosition {
+    type Variant = BoardVariant;
+
+    fn variant(&self) -> BoardVariant {
+        BoardVariant {
+            width: self.width(),
+            height: self.height(),
+            k_in_a_row: self.k_in_a_row,
+        }
+    }
+
+    fn index_count(variant: &BoardVariant) -> u64 {
+        let bits = (variant.height + 1) * variant.width;
+
+        assert!(
+            bits < 64,
+            "Board should be small enough to index with a u64"
+        );
+
+        1 << bits
+    }
+
+    fn to_index(&self) -> u64 {
+        let column_bits = self.height() + 1;
+
+        (0..self.width()).rev().fold(0, |index, column| {
+            let pieces = (0..self.height())
+                .map_while(|row| self.board[row][column])
+                .collect::<Vec<_>>();
+
+            let code = pieces
+                .iter()
+                .enumerate()
+                .fold(1 << pieces.len(), |code, (row, piece)| match piece {
+                    ConnectFourPlayer::X => code,
+                    ConnectFourPlayer::O => code | (1 << row),
+                });
+
+            (index << column_bits) | code
+        })
+    }
+
+    fn from_index(variant: &BoardVariant, index: u64) -> Self {
+        let column_bits = variant.height + 1;
+        let column_mask = (1 << column_bits) - 1;
+
+        let mut board = vec![vec![None; variant.width]; variant.height];
+        let mut piece_count = 0;
+
+        for column in 0..variant.width {
+            let code: u64 = (index >> (column * column_bits)) & column_mask;
+            let pieces = match code {
+                0 => 0,
+                _ => 63 - code.leading_zeros() as usize,
+            };
+
+            piece_count += pieces;
+
+            for (row, line) in board.iter_mut().enumerate().take(pieces) {
+                line[column] = match (code >> row) & 1 {
+                    0 => Some(ConnectFourPlayer::X),
+                    _ => Some(ConnectFourPlayer::O),
+                };
+            }
+        }
+
+        ConnectFourPosition {
+            k_in_a_row: variant.k_in_a_row,
+            board,
+            player: match piece_count % 2 {
+                0 => ConnectFourPlayer::X,
+                _ => ConnectFourPlayer::O,
+            },
+        }
+    }
+}
+
+impl DatabasePosition for ConnectFourPosition {
+    fn game_id() -> String {
+        "connect_four".to_string()
+    }
+
+    fn variant_params(variant: &BoardVariant) -> Vec<u64> {
+        variant.to_params()
+    }
+}
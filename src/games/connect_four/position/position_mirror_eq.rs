@@ -0,0 +1,20 @@
+use crate::games::board::reflect_along_y;
+use crate::games::symmetric::{Mirror, SymmetricPosition, Symmetry};
+
+use super::ConnectFourPosition;
+
+/// Positions that are left-right mirror images of each other are equal.
+/// Other board symmetries do not respect gravity.
+pub type ConnectFourPositionMirrorEq = SymmetricPosition<ConnectFourPosition, Mirror>;
+
+impl Symmetry<Mirror> for ConnectFourPosition {
+    fn transforms(&self) -> Vec<Self> {
+        let mirror = ConnectFourPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: reflect_along_y(&self.board),
+            player: self.player,
+        };
+
+        vec![self.clone(), mirror]
+    }
+}
@@ -0,0 +1,13 @@
+use crate::games::connect_four::ConnectFourPrimitiveValue;
+use crate::solver::{GameResult, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResult> for ConnectFourPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResult> {
+        match self {
+            ConnectFourPrimitiveValue::_Win => Some(GameResult::Win),
+            ConnectFourPrimitiveValue::Lose => Some(GameResult::Lose),
+            ConnectFourPrimitiveValue::Tie => Some(GameResult::Tie),
+            ConnectFourPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
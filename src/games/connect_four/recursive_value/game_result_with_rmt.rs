@@ -0,0 +1,22 @@
+use crate::games::connect_four::ConnectFourPrimitiveValue;
+use crate::solver::{GameResult, GameResultWithRmt, RmtU32, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResultWithRmt> for ConnectFourPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResultWithRmt> {
+        match self {
+            ConnectFourPrimitiveValue::_Win => Some(GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(0),
+            }),
+            ConnectFourPrimitiveValue::Lose => Some(GameResultWithRmt {
+                game_result: GameResult::Lose,
+                rmt: RmtU32::Val(0),
+            }),
+            ConnectFourPrimitiveValue::Tie => Some(GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(0),
+            }),
+            ConnectFourPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
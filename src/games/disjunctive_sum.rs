@@ -0,0 +1,177 @@
+use crate::export::ExportPosition;
+use crate::solver::{
+    GameResult, Nimber, PlayerMove, Position, PositionKey, PrimitiveValue, ToRecursiveValue,
+};
+
+/// Several impartial games played side by side: each turn is a move in
+/// exactly one component, and whoever cannot move loses.
+///
+/// Solving this directly searches the whole product space. It exists mostly
+/// to check `Solver::solve_disjunctive_sum`, which does not.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct DisjunctiveSum<P> {
+    pub components: Vec<P>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DisjunctiveSumMove<M> {
+    pub index: usize,
+    pub mov: M,
+}
+
+/// The primitive values of every component.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DisjunctiveSumPrimitiveValue<PV> {
+    pub components: Vec<PV>,
+}
+
+impl<M> PlayerMove for DisjunctiveSumMove<M> where M: PlayerMove {}
+
+impl<PV> PrimitiveValue for DisjunctiveSumPrimitiveValue<PV>
+where
+    PV: PrimitiveValue,
+{
+    fn is_primitive(&self) -> bool {
+        self.components
+            .iter()
+            .all(|component| component.is_primitive())
+    }
+}
+
+impl<PV> ToRecursiveValue<GameResult> for DisjunctiveSumPrimitiveValue<PV>
+where
+    PV: PrimitiveValue,
+{
+    fn to_recursive_value(&self) -> Option<GameResult> {
+        match self.is_primitive() {
+            true => Some(GameResult::Lose),
+            false => None,
+        }
+    }
+}
+
+impl<PV> ToRecursiveValue<Nimber> for DisjunctiveSumPrimitiveValue<PV>
+where
+    PV: PrimitiveValue,
+{
+    fn to_recursive_value(&self) -> Option<Nimber> {
+        match self.is_primitive() {
+            true => Some(Nimber(0)),
+            false => None,
+        }
+    }
+}
+
+impl<P> PositionKey for DisjunctiveSum<P> where P: PositionKey {}
+
+/// Components joined by `" + "`, e.g. `"3 + 3/4"`.
+impl<P> ExportPosition for DisjunctiveSum<P>
+where
+    P: ExportPosition,
+{
+    fn board_string(&self) -> String {
+        self.components
+            .iter()
+            .map(|component| component.board_string())
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Components are assumed to be normal play games, i.e. a component is
+/// primitive exactly when it has no moves left.
+impl<P, M, PV> Position<DisjunctiveSumMove<M>, DisjunctiveSumPrimitiveValue<PV>>
+    for DisjunctiveSum<P>
+where
+    P: Position<M, PV> + Clone,
+    M: PlayerMove,
+    PV: PrimitiveValue,
+{
+    fn do_move(&self, mov: DisjunctiveSumMove<M>) -> DisjunctiveSum<P> {
+        let mut components = self.components.clone();
+
+        components[mov.index] = components[mov.index].do_move(mov.mov);
+
+        DisjunctiveSum { components }
+    }
+
+    fn generate_moves(&self) -> Vec<DisjunctiveSumMove<M>> {
+        self.components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| !component.primitive_value().is_primitive())
+            .flat_map(|(index, component)| {
+                component
+                    .generate_moves()
+                    .into_iter()
+                    .map(move |mov| DisjunctiveSumMove { index, mov })
+            })
+            .collect()
+    }
+
+    fn primitive_value(&self) -> DisjunctiveSumPrimitiveValue<PV> {
+        DisjunctiveSumPrimitiveValue {
+            components: self
+                .components
+                .iter()
+                .map(|component| component.primitive_value())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::DisjunctiveSum;
+    use crate::games::subtraction::SubtractionGame;
+    use crate::solver::{GameResult, Nimber, Solver, ToGameResult};
+
+    #[test]
+    fn same_as_product_space() {
+        let game = SubtractionGame::new(&[1, 3, 4]);
+
+        let mut component_solver = Solver::<_, _, _, Nimber>::new(HashMap::new());
+        let mut sum_solver = Solver::<_, _, _, Nimber>::new(HashMap::new());
+        let mut game_result_solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        for a in 0..8 {
+            for b in 0..8 {
+                for c in 0..8 {
+                    let components = vec![game.position(a), game.position(b), game.position(c)];
+
+                    let nimber = component_solver.solve_disjunctive_sum(components.clone());
+
+                    assert_eq!(
+                        nimber,
+                        sum_solver.solve(DisjunctiveSum {
+                            components: components.clone()
+                        })
+                    );
+                    assert_eq!(
+                        nimber.to_game_result(),
+                        game_result_solver.solve(DisjunctiveSum { components })
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ten_piles() {
+        let game = SubtractionGame::new(&[1, 2, 3]);
+        let mut solver = Solver::<_, _, _, Nimber>::new(HashMap::new());
+
+        // Take 1 to 3 has nimber n % 4.
+        let nimber = solver.solve_disjunctive_sum((1..=10).map(|n| game.position(n * 100)));
+        assert_eq!(nimber, Nimber(0));
+
+        let nimber = solver.solve_disjunctive_sum((1..=10).map(|n| game.position(n * 101)));
+        assert_eq!(nimber, Nimber(3));
+    }
+}
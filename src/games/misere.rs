@@ -0,0 +1,157 @@
+use crate::export::ExportPosition;
+use crate::solver::{
+    FlipWinLose, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue, Tier,
+    ToRecursiveValue,
+};
+
+/// The misère version of any game: every primitive `Win` becomes a `Lose`
+/// and the other way around, so e.g. the last player to move loses.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Misere<P> {
+    pub position: P,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MiserePrimitiveValue<PV> {
+    pub primitive_value: PV,
+}
+
+impl<PV> PrimitiveValue for MiserePrimitiveValue<PV>
+where
+    PV: PrimitiveValue,
+{
+    fn is_primitive(&self) -> bool {
+        self.primitive_value.is_primitive()
+    }
+}
+
+impl<PV, RV> ToRecursiveValue<RV> for MiserePrimitiveValue<PV>
+where
+    PV: ToRecursiveValue<RV>,
+    RV: RecursiveValue + FlipWinLose,
+{
+    fn to_recursive_value(&self) -> Option<RV> {
+        self.primitive_value
+            .to_recursive_value()
+            .map(|result| result.flip_win_lose())
+    }
+}
+
+impl<P> PositionKey for Misere<P> where P: PositionKey {}
+
+impl<P> ExportPosition for Misere<P>
+where
+    P: ExportPosition,
+{
+    fn board_string(&self) -> String {
+        self.position.board_string()
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        self.position.player_to_move()
+    }
+}
+
+impl<P> Tier for Misere<P>
+where
+    P: Tier,
+{
+    fn tier(&self) -> u32 {
+        self.position.tier()
+    }
+}
+
+impl<P, M, PV> Position<M, MiserePrimitiveValue<PV>> for Misere<P>
+where
+    P: Position<M, PV>,
+    M: PlayerMove,
+    PV: PrimitiveValue,
+{
+    fn do_move(&self, mov: M) -> Misere<P> {
+        Misere {
+            position: self.position.do_move(mov),
+        }
+    }
+
+    fn generate_moves(&self) -> Vec<M> {
+        self.position.generate_moves()
+    }
+
+    fn primitive_value(&self) -> MiserePrimitiveValue<PV> {
+        MiserePrimitiveValue {
+            primitive_value: self.position.primitive_value(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::Misere;
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver};
+
+    #[test]
+    fn take_10_to_0() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        for i in (0..=30).rev() {
+            let result = solver.solve(Misere {
+                position: take_10_to_0::position(i),
+            });
+
+            match i % 3 {
+                1 => assert_eq!(result, GameResult::Lose),
+                _ => assert_eq!(result, GameResult::Win),
+            };
+        }
+    }
+
+    #[test]
+    fn take_10_to_0_with_rmt() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve(Misere {
+            position: take_10_to_0::position(0),
+        });
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(0),
+            }
+        );
+
+        let result = solver.solve(Misere {
+            position: take_10_to_0::position(10),
+        });
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Lose,
+                rmt: RmtU32::Val(7),
+            }
+        );
+    }
+
+    #[test]
+    fn tic_tac_toe() {
+        let start = TicTacToePosition::start;
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(solver.solve(Misere { position: start() }), GameResult::Tie);
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve(Misere {
+            position: TicTacToePositionD4Eq::new(start()),
+        });
+
+        assert_eq!(result.game_result, GameResult::Tie);
+    }
+}
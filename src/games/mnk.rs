@@ -0,0 +1,106 @@
+mod position;
+mod recursive_value;
+
+pub use self::position::MnkPlayer;
+pub use self::position::MnkPosition;
+pub use self::position::MnkPositionSymEq;
+
+use crate::solver::{PlayerMove, PrimitiveValue};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MnkPrimitiveValue {
+    _Win,
+    Lose,
+    Tie,
+    NotPrimitive,
+}
+
+impl PrimitiveValue for MnkPrimitiveValue {
+    fn is_primitive(&self) -> bool {
+        !matches!(self, MnkPrimitiveValue::NotPrimitive)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MnkMove {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl PlayerMove for MnkMove {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{MnkPosition, MnkPositionSymEq};
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::tic_tac_toe_non_sq::{
+        TicTacToeNonSqPlayer, TicTacToeNonSqPosition, TicTacToeNonSqPositionVEq,
+    };
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver};
+
+    #[test]
+    fn same_as_tic_tac_toe() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut tic_tac_toe_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            solver.solve(MnkPositionSymEq::new(MnkPosition::start(3, 3, 3))),
+            tic_tac_toe_solver.solve(TicTacToePositionD4Eq::new(TicTacToePosition::start()))
+        );
+        assert_eq!(solver.len(), tic_tac_toe_solver.len());
+    }
+
+    #[test]
+    fn same_as_tic_tac_toe_non_sq() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut non_sq_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            solver.solve(MnkPositionSymEq::new(MnkPosition::start(4, 3, 3))),
+            non_sq_solver.solve(TicTacToeNonSqPositionVEq::new(TicTacToeNonSqPosition {
+                board: [[None; 4]; 3],
+                player: TicTacToeNonSqPlayer::X,
+            }))
+        );
+        assert_eq!(solver.len(), non_sq_solver.len());
+    }
+
+    #[test]
+    fn sweep_board_sizes() {
+        for width in 1..=3 {
+            for height in 1..=3 {
+                for k_in_a_row in 1..=4 {
+                    let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+                    let mut sym_eq_solver =
+                        Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+                    let result = solver.solve(MnkPosition::start(width, height, k_in_a_row));
+
+                    assert_eq!(
+                        result,
+                        sym_eq_solver.solve(MnkPositionSymEq::new(MnkPosition::start(
+                            width, height, k_in_a_row
+                        )))
+                    );
+                    assert!(sym_eq_solver.len() <= solver.len());
+
+                    if k_in_a_row == 1 {
+                        assert_eq!(
+                            result,
+                            GameResultWithRmt {
+                                game_result: GameResult::Win,
+                                rmt: RmtU32::Val(1),
+                            }
+                        );
+                    }
+
+                    if k_in_a_row > width.max(height) {
+                        assert_eq!(result.game_result, GameResult::Tie);
+                    }
+                }
+            }
+        }
+    }
+}
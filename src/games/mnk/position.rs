@@ -0,0 +1,116 @@
+use crate::export::ExportPosition;
+use crate::games::board::{board_string, has_k_in_a_row};
+use crate::solver::{Position, PositionKey, Tier};
+
+use super::{MnkMove, MnkPrimitiveValue};
+
+mod position_hash;
+mod position_sym_eq;
+
+pub use position_sym_eq::MnkPositionSymEq;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum MnkPlayer {
+    X,
+    O,
+}
+
+impl MnkPlayer {
+    fn next_player(&self) -> MnkPlayer {
+        match self {
+            MnkPlayer::X => MnkPlayer::O,
+            MnkPlayer::O => MnkPlayer::X,
+        }
+    }
+}
+
+/// `board[x][y]` is the cell in row `x` and column `y`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct MnkPosition {
+    pub k_in_a_row: usize,
+    pub board: Vec<Vec<Option<MnkPlayer>>>,
+    pub player: MnkPlayer,
+}
+
+impl MnkPosition {
+    pub fn start(width: usize, height: usize, k_in_a_row: usize) -> MnkPosition {
+        assert!(width >= 1 && height >= 1, "Board should not be empty");
+        assert!(k_in_a_row >= 1, "K_IN_A_ROW should be at least 1");
+
+        MnkPosition {
+            k_in_a_row,
+            board: vec![vec![None; width]; height],
+            player: MnkPlayer::X,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.board[0].len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.board.len()
+    }
+}
+
+impl PositionKey for MnkPosition {}
+
+impl ExportPosition for MnkPosition {
+    fn board_string(&self) -> String {
+        board_string(self.board.iter().map(|row| &row[..]))
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        Some(format!("{:?}", self.player))
+    }
+}
+
+impl Tier for MnkPosition {
+    fn tier(&self) -> u32 {
+        self.board
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_some())
+            .count() as u32
+    }
+}
+
+impl Position<MnkMove, MnkPrimitiveValue> for MnkPosition {
+    fn do_move(&self, mov: MnkMove) -> MnkPosition {
+        let mut board = self.board.clone();
+
+        board[mov.x][mov.y] = Some(self.player);
+
+        MnkPosition {
+            k_in_a_row: self.k_in_a_row,
+            board,
+            player: self.player.next_player(),
+        }
+    }
+
+    fn generate_moves(&self) -> Vec<MnkMove> {
+        let mut moves = vec![];
+
+        self.board.iter().enumerate().for_each(|(i, line)| {
+            line.iter().enumerate().for_each(|(j, cell)| {
+                if cell.is_none() {
+                    moves.push(MnkMove { x: i, y: j });
+                }
+            })
+        });
+
+        moves
+    }
+
+    fn primitive_value(&self) -> MnkPrimitiveValue {
+        if has_k_in_a_row(&self.board, self.k_in_a_row, &self.player.next_player()) {
+            return MnkPrimitiveValue::Lose;
+        }
+
+        if self.board.iter().flatten().any(|cell| cell.is_none()) {
+            return MnkPrimitiveValue::NotPrimitive;
+        }
+
+        MnkPrimitiveValue::Tie
+    }
+}
@@ -0,0 +1,67 @@
+use crate::database::DatabasePosition;
+use crate::games::board::{base_3_index_count, rank_base_3, unrank_base_3, BoardVariant};
+use crate::solver::PositionHash;
+
+use super::{MnkPlayer, MnkPosition};
+
+impl PositionHash for MnkPosition {
+    type Variant = BoardVariant;
+
+    fn variant(&self) -> BoardVariant {
+        BoardVariant {
+            width: self.width(),
+            height: self.height(),
+            k_in_a_row: self.k_in_a_row,
+        }
+    }
+
+    fn index_count(variant: &BoardVariant) -> u64 {
+        base_3_index_count(variant.width * variant.height)
+    }
+
+    fn to_index(&self) -> u64 {
+        let digits = self.board.iter().flatten().map(|cell| match cell {
+            None => 0,
+            Some(MnkPlayer::X) => 1,
+            Some(MnkPlayer::O) => 2,
+        });
+
+        rank_base_3(digits)
+    }
+
+    fn from_index(variant: &BoardVariant, index: u64) -> Self {
+        let (digits, second_player) = unrank_base_3(index, variant.width * variant.height);
+
+        let board = digits
+            .chunks(variant.width)
+            .map(|row| {
+                row.iter()
+                    .map(|digit| match digit {
+                        0 => None,
+                        1 => Some(MnkPlayer::X),
+                        _ => Some(MnkPlayer::O),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        MnkPosition {
+            k_in_a_row: variant.k_in_a_row,
+            board,
+            player: match second_player {
+                false => MnkPlayer::X,
+                true => MnkPlayer::O,
+            },
+        }
+    }
+}
+
+impl DatabasePosition for MnkPosition {
+    fn game_id() -> String {
+        "mnk".to_string()
+    }
+
+    fn variant_params(variant: &BoardVariant) -> Vec<u64> {
+        variant.to_params()
+    }
+}
@@ -0,0 +1,44 @@
+use crate::games::board;
+use crate::games::symmetric::{BoardSymmetry, Grid, SquareGrid, SymmetricPosition};
+
+use super::MnkPosition;
+
+/// Positions related by a symmetry of the board are equal: the dihedral
+/// group D4 for square boards, the Klein four-group otherwise.
+pub type MnkPositionSymEq = SymmetricPosition<MnkPosition, BoardSymmetry>;
+
+impl Grid for MnkPosition {
+    fn width(&self) -> usize {
+        MnkPosition::width(self)
+    }
+
+    fn height(&self) -> usize {
+        MnkPosition::height(self)
+    }
+
+    fn reflect_along_x(&self) -> Self {
+        MnkPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: board::reflect_along_x(&self.board),
+            player: self.player,
+        }
+    }
+
+    fn reflect_along_y(&self) -> Self {
+        MnkPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: board::reflect_along_y(&self.board),
+            player: self.player,
+        }
+    }
+}
+
+impl SquareGrid for MnkPosition {
+    fn transpose(&self) -> Self {
+        MnkPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: board::transpose(&self.board),
+            player: self.player,
+        }
+    }
+}
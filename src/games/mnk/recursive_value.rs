@@ -0,0 +1,2 @@
+mod game_result;
+mod game_result_with_rmt;
@@ -0,0 +1,13 @@
+use crate::games::mnk::MnkPrimitiveValue;
+use crate::solver::{GameResult, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResult> for MnkPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResult> {
+        match self {
+            MnkPrimitiveValue::_Win => Some(GameResult::Win),
+            MnkPrimitiveValue::Lose => Some(GameResult::Lose),
+            MnkPrimitiveValue::Tie => Some(GameResult::Tie),
+            MnkPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
@@ -1,22 +1,22 @@
-use crate::games::take_10_to_0::TenToZeroPrimitiveValue;
+use crate::games::mnk::MnkPrimitiveValue;
 use crate::solver::{GameResult, GameResultWithRmt, RmtU32, ToRecursiveValue};
 
-impl ToRecursiveValue<GameResultWithRmt> for TenToZeroPrimitiveValue {
+impl ToRecursiveValue<GameResultWithRmt> for MnkPrimitiveValue {
     fn to_recursive_value(&self) -> Option<GameResultWithRmt> {
         match self {
-            TenToZeroPrimitiveValue::_Win => Some(GameResultWithRmt {
+            MnkPrimitiveValue::_Win => Some(GameResultWithRmt {
                 game_result: GameResult::Win,
                 rmt: RmtU32::Val(0),
             }),
-            TenToZeroPrimitiveValue::Lose => Some(GameResultWithRmt {
+            MnkPrimitiveValue::Lose => Some(GameResultWithRmt {
                 game_result: GameResult::Lose,
                 rmt: RmtU32::Val(0),
             }),
-            TenToZeroPrimitiveValue::_Tie => Some(GameResultWithRmt {
+            MnkPrimitiveValue::Tie => Some(GameResultWithRmt {
                 game_result: GameResult::Tie,
                 rmt: RmtU32::Val(0),
             }),
-            TenToZeroPrimitiveValue::NotPrimitive => None,
+            MnkPrimitiveValue::NotPrimitive => None,
         }
     }
 }
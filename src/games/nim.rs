@@ -0,0 +1,146 @@
+mod position;
+mod recursive_value;
+
+pub use self::position::NimPosition;
+pub use self::position::NimPositionPermEq;
+
+use crate::solver::{PlayerMove, PrimitiveValue};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NimPrimitiveValue {
+    _Win,
+    Lose,
+    _Tie,
+    NotPrimitive,
+}
+
+impl PrimitiveValue for NimPrimitiveValue {
+    fn is_primitive(&self) -> bool {
+        !matches!(self, NimPrimitiveValue::NotPrimitive)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NimMove {
+    pub heap: usize,
+    pub amount: u32,
+}
+
+impl PlayerMove for NimMove {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::{NimPosition, NimPositionPermEq};
+    use crate::games::symmetric::{Permutation, Symmetry};
+    use crate::solver::{GameResult, Nimber, Solver};
+
+    fn all_heaps(heap_count: usize, max_heap: u32) -> Vec<Vec<u32>> {
+        (0..heap_count).fold(vec![vec![]], |heaps_list, _| {
+            heaps_list
+                .iter()
+                .flat_map(|heaps| {
+                    (0..=max_heap).map(move |heap| {
+                        let mut heaps = heaps.clone();
+                        heaps.push(heap);
+                        heaps
+                    })
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn xor_rule() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut nimber_solver = Solver::<_, _, _, Nimber>::new(HashMap::new());
+
+        for heaps in all_heaps(3, 5) {
+            let xor = heaps.iter().fold(0, |xor, heap| xor ^ heap);
+
+            let result = solver.solve(NimPosition {
+                heaps: heaps.clone(),
+            });
+
+            match xor {
+                0 => assert_eq!(result, GameResult::Lose),
+                _ => assert_eq!(result, GameResult::Win),
+            };
+
+            assert_eq!(nimber_solver.solve(NimPosition { heaps }), Nimber(xor));
+        }
+    }
+
+    #[test]
+    fn perm_eq() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut perm_eq_solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        for heaps in all_heaps(3, 4) {
+            assert_eq!(
+                solver.solve(NimPosition {
+                    heaps: heaps.clone()
+                }),
+                perm_eq_solver.solve(NimPositionPermEq::new(NimPosition { heaps }))
+            );
+        }
+
+        // 5^3 orderings, but only C(5 + 2, 3) multisets.
+        assert_eq!(125, solver.len());
+        assert_eq!(35, perm_eq_solver.len());
+    }
+
+    #[test]
+    fn canonical_is_smallest_permutation() {
+        for heaps in all_heaps(3, 2) {
+            let position = NimPosition { heaps };
+            let transforms = Symmetry::<Permutation>::transforms(&position);
+
+            // 3! orderings, less those that only swap equal heaps.
+            let distinct = match position.heaps.iter().collect::<HashSet<_>>().len() {
+                1 => 1,
+                2 => 3,
+                _ => 6,
+            };
+
+            assert_eq!(distinct, transforms.len());
+            assert_eq!(distinct, transforms.iter().collect::<HashSet<_>>().len());
+            assert_eq!(
+                transforms.into_iter().min().as_ref(),
+                Some(NimPositionPermEq::new(position).canonical())
+            );
+        }
+
+        // 10! orderings, but only C(10, 5) distinct ones.
+        let position = NimPosition {
+            heaps: vec![1, 2, 1, 2, 1, 2, 1, 2, 1, 2],
+        };
+
+        assert_eq!(252, Symmetry::<Permutation>::transforms(&position).len());
+    }
+
+    #[test]
+    fn xor_rule_for_every_solve_mode() {
+        for heaps in all_heaps(3, 4) {
+            let expected = match heaps.iter().fold(0, |xor, heap| xor ^ heap) {
+                0 => GameResult::Lose,
+                _ => GameResult::Win,
+            };
+
+            let start = || {
+                NimPositionPermEq::new(NimPosition {
+                    heaps: heaps.clone(),
+                })
+            };
+
+            let solver = || Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+            assert_eq!(expected, solver().solve_iterative(start()));
+            assert_eq!(expected, solver().solve_retrograde(start()));
+            assert_eq!(expected, solver().solve_value_only(start()));
+            assert_eq!(expected, solver().solve_parallel(start(), 2));
+            assert_eq!(expected, solver().solve_by_tier(start()));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::export::ExportPosition;
+use crate::solver::{Position, PositionKey, Tier};
+
+use super::{NimMove, NimPrimitiveValue};
+
+mod position_perm_eq;
+
+pub use position_perm_eq::NimPositionPermEq;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct NimPosition {
+    pub heaps: Vec<u32>,
+}
+
+impl PositionKey for NimPosition {}
+
+/// Heap sizes joined by `/`, e.g. `"3/4/5"`.
+impl ExportPosition for NimPosition {
+    fn board_string(&self) -> String {
+        self.heaps
+            .iter()
+            .map(|heap| heap.to_string())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        None
+    }
+}
+
+impl Tier for NimPosition {
+    fn tier(&self) -> u32 {
+        self.heaps.iter().sum()
+    }
+}
+
+impl Position<NimMove, NimPrimitiveValue> for NimPosition {
+    fn do_move(&self, mov: NimMove) -> NimPosition {
+        let mut heaps = self.heaps.clone();
+
+        heaps[mov.heap] -= mov.amount;
+
+        NimPosition { heaps }
+    }
+
+    fn generate_moves(&self) -> Vec<NimMove> {
+        self.heaps
+            .iter()
+            .enumerate()
+            .flat_map(|(heap, &size)| (1..=size).map(move |amount| NimMove { heap, amount }))
+            .collect()
+    }
+
+    fn primitive_value(&self) -> NimPrimitiveValue {
+        match self.heaps.iter().all(|&size| size == 0) {
+            true => NimPrimitiveValue::Lose,
+            false => NimPrimitiveValue::NotPrimitive,
+        }
+    }
+}
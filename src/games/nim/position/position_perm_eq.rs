@@ -0,0 +1,59 @@
+use crate::games::symmetric::{Permutation, SymmetricPosition, Symmetry};
+
+use super::NimPosition;
+
+/// Positions whose heaps are permutations of each other are equal.
+///
+/// Positions are only ever compared by `canonical`, which sorts the heaps.
+/// `transforms` lists every distinct ordering, which is still far too many
+/// for more than a handful of heaps.
+pub type NimPositionPermEq = SymmetricPosition<NimPosition, Permutation>;
+
+impl Symmetry<Permutation> for NimPosition {
+    /// Every distinct ordering of the heaps, in ascending order.
+    fn transforms(&self) -> Vec<Self> {
+        let mut heaps = self.heaps.clone();
+
+        heaps.sort();
+
+        let mut transforms = vec![NimPosition {
+            heaps: heaps.clone(),
+        }];
+
+        while next_permutation(&mut heaps) {
+            transforms.push(NimPosition {
+                heaps: heaps.clone(),
+            });
+        }
+
+        transforms
+    }
+
+    /// The heaps in ascending order, without listing every permutation.
+    fn canonical(&self) -> Self {
+        let mut heaps = self.heaps.clone();
+
+        heaps.sort();
+
+        NimPosition { heaps }
+    }
+}
+
+/// Rearranges `heaps` into the next larger ordering, or returns `false` if
+/// it is already the largest. Equal heaps are never swapped with each other,
+/// so no ordering comes up twice.
+fn next_permutation(heaps: &mut [u32]) -> bool {
+    let Some(i) = heaps.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        return false;
+    };
+
+    let j = heaps
+        .iter()
+        .rposition(|&heap| heap > heaps[i])
+        .expect("heaps[i + 1] is larger");
+
+    heaps.swap(i, j);
+    heaps[i + 1..].reverse();
+
+    true
+}
@@ -0,0 +1,13 @@
+use crate::games::nim::NimPrimitiveValue;
+use crate::solver::{GameResult, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResult> for NimPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResult> {
+        match self {
+            NimPrimitiveValue::_Win => Some(GameResult::Win),
+            NimPrimitiveValue::Lose => Some(GameResult::Lose),
+            NimPrimitiveValue::_Tie => Some(GameResult::Tie),
+            NimPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::games::nim::NimPrimitiveValue;
+use crate::solver::{GameResult, GameResultWithRmt, RmtU32, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResultWithRmt> for NimPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResultWithRmt> {
+        match self {
+            NimPrimitiveValue::_Win => Some(GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(0),
+            }),
+            NimPrimitiveValue::Lose => Some(GameResultWithRmt {
+                game_result: GameResult::Lose,
+                rmt: RmtU32::Val(0),
+            }),
+            NimPrimitiveValue::_Tie => Some(GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(0),
+            }),
+            NimPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::games::nim::NimPrimitiveValue;
+use crate::solver::{Nimber, ToRecursiveValue};
+
+impl ToRecursiveValue<Nimber> for NimPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<Nimber> {
+        match self {
+            NimPrimitiveValue::Lose => Some(Nimber(0)),
+            NimPrimitiveValue::NotPrimitive => None,
+            _ => panic!("Nimbers are only defined for normal play"),
+        }
+    }
+}
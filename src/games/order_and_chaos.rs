@@ -3,28 +3,67 @@ mod recursive_value;
 
 pub use self::position::OrderAndChaosPlayer;
 pub use self::position::OrderAndChaosPosition;
+pub use self::position::OrderAndChaosPositionColorSymEq;
 pub use self::position::OrderAndChaosPositionD4Eq;
+pub use self::position::OrderAndChaosPositionSymEq;
 pub use self::position::OrderAndChaosPositionVEq;
 
 use crate::solver::{PlayerMove, PrimitiveValue};
 
-const WIDTH: usize = 3;
-const HEIGHT: usize = 3;
-const K_IN_A_ROW: usize = 3;
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OrderAndChaosGame {
+    width: usize,
+    height: usize,
+    k_in_a_row: usize,
+}
+
+impl OrderAndChaosGame {
+    pub fn new(width: usize, height: usize, k_in_a_row: usize) -> OrderAndChaosGame {
+        // Check bounds
+        assert!((1..=10).contains(&width), "WIDTH should be in 1..=10");
+        assert!((1..=10).contains(&height), "HEIGHT should be in 1..=10");
+        assert!(
+            (1..=10).contains(&k_in_a_row),
+            "K_IN_A_ROW should be in 1..=10"
+        );
 
-// Check bounds
-const _: () = assert!(WIDTH <= 10);
-const _: () = assert!(HEIGHT <= 10);
-const _: () = assert!(K_IN_A_ROW <= 10 && K_IN_A_ROW >= 1);
+        OrderAndChaosGame {
+            width,
+            height,
+            k_in_a_row,
+        }
+    }
 
-// Start position
-impl OrderAndChaosPosition {
-    pub fn start() -> OrderAndChaosPosition {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn k_in_a_row(&self) -> usize {
+        self.k_in_a_row
+    }
+
+    pub fn start(&self) -> OrderAndChaosPosition {
         OrderAndChaosPosition {
-            board: [[None; WIDTH]; HEIGHT],
+            k_in_a_row: self.k_in_a_row,
+            board: vec![vec![None; self.width]; self.height],
             player: OrderAndChaosPlayer::Order,
         }
     }
+
+    /// The start position wrapped in the largest symmetry group valid for
+    /// this board: D4 for square boards, the Klein four-group otherwise.
+    pub fn start_sym_eq(&self) -> OrderAndChaosPositionSymEq {
+        OrderAndChaosPositionSymEq::new(self.start())
+    }
+
+    /// Like `start_sym_eq`, but X and O are also interchangeable.
+    pub fn start_color_sym_eq(&self) -> OrderAndChaosPositionColorSymEq {
+        OrderAndChaosPositionColorSymEq::new(self.start())
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -37,10 +76,7 @@ pub enum OrderAndChaosPrimitiveValue {
 
 impl PrimitiveValue for OrderAndChaosPrimitiveValue {
     fn is_primitive(&self) -> bool {
-        match self {
-            OrderAndChaosPrimitiveValue::NotPrimitive => false,
-            _ => true,
-        }
+        !matches!(self, OrderAndChaosPrimitiveValue::NotPrimitive)
     }
 }
 
@@ -50,7 +86,7 @@ pub enum OrderAndChaosPiece {
     O,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct OrderAndChaosMove {
     piece: OrderAndChaosPiece,
     x: usize,
@@ -63,20 +99,101 @@ impl PlayerMove for OrderAndChaosMove {}
 mod tests {
     use std::collections::HashMap;
 
-    use super::OrderAndChaosPosition;
-    use crate::{
-        games::order_and_chaos::OrderAndChaosPositionD4Eq,
-        solver::{GameResult, Solver},
-    };
+    use super::{OrderAndChaosGame, OrderAndChaosPositionD4Eq, OrderAndChaosPositionVEq};
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver};
 
     #[test]
     fn test_order_and_chaos() {
         let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
 
-        let result = solver.solve(OrderAndChaosPositionD4Eq {
-            position: OrderAndChaosPosition::start(),
-        });
+        let result = solver.solve(OrderAndChaosGame::new(3, 3, 3).start_sym_eq());
 
         println!("{:?}", result);
     }
+
+    #[test]
+    fn symmetry_chosen_by_board_shape() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut d4_eq_solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        solver.solve(OrderAndChaosGame::new(3, 3, 3).start_sym_eq());
+        d4_eq_solver.solve(OrderAndChaosPositionD4Eq::new(
+            OrderAndChaosGame::new(3, 3, 3).start(),
+        ));
+
+        assert_eq!(solver.len(), d4_eq_solver.len());
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut v_eq_solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        solver.solve(OrderAndChaosGame::new(3, 2, 2).start_sym_eq());
+        v_eq_solver.solve(OrderAndChaosPositionVEq::new(
+            OrderAndChaosGame::new(3, 2, 2).start(),
+        ));
+
+        assert_eq!(solver.len(), v_eq_solver.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "Symmetry group should apply to the position")]
+    fn d4_eq_rejects_non_square_board() {
+        OrderAndChaosPositionD4Eq::new(OrderAndChaosGame::new(4, 3, 3).start());
+    }
+
+    #[test]
+    fn three_by_three() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve(OrderAndChaosGame::new(3, 3, 3).start_sym_eq());
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(5),
+            }
+        );
+    }
+
+    #[test]
+    fn four_by_four_and_four_by_three() {
+        for (width, height) in [(4, 4), (4, 3)] {
+            let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+            let result =
+                solver.solve_value_only(OrderAndChaosGame::new(width, height, 3).start_sym_eq());
+
+            assert_eq!(result, GameResult::Win);
+        }
+    }
+
+    #[test]
+    fn color_sym_eq_same_root_value() {
+        let game = OrderAndChaosGame::new(3, 3, 3);
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut color_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            solver.solve(game.start_sym_eq()),
+            color_solver.solve(game.start_color_sym_eq())
+        );
+
+        assert_eq!((solver.len(), color_solver.len()), (2694, 1359));
+    }
+
+    #[test]
+    fn color_sym_eq_same_root_value_non_square() {
+        let game = OrderAndChaosGame::new(3, 2, 2);
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut color_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            solver.solve(game.start_sym_eq()),
+            color_solver.solve(game.start_color_sym_eq())
+        );
+
+        assert_eq!((solver.len(), color_solver.len()), (105, 56));
+    }
 }
@@ -1,14 +1,17 @@
-use crate::solver::{Position, PositionKey};
-
-mod position_d4_eq;
-pub use position_d4_eq::OrderAndChaosPositionD4Eq;
-mod position_v_eq;
-pub use position_v_eq::OrderAndChaosPositionVEq;
-
-use super::{
-    OrderAndChaosMove, OrderAndChaosPiece, OrderAndChaosPrimitiveValue, HEIGHT, K_IN_A_ROW, WIDTH,
+use crate::export::ExportPosition;
+use crate::games::board::{board_string, has_k_in_a_row};
+use crate::solver::{Position, PositionKey, Tier};
+
+mod position_color_sym_eq;
+pub use position_color_sym_eq::OrderAndChaosPositionColorSymEq;
+mod position_hash;
+mod position_sym_eq;
+pub use position_sym_eq::{
+    OrderAndChaosPositionD4Eq, OrderAndChaosPositionSymEq, OrderAndChaosPositionVEq,
 };
 
+use super::{OrderAndChaosMove, OrderAndChaosPiece, OrderAndChaosPrimitiveValue};
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub enum OrderAndChaosPlayer {
     Order,
@@ -26,12 +29,47 @@ impl OrderAndChaosPlayer {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct OrderAndChaosPosition {
-    pub board: [[Option<OrderAndChaosPiece>; WIDTH]; HEIGHT],
+    pub k_in_a_row: usize,
+    pub board: Vec<Vec<Option<OrderAndChaosPiece>>>,
     pub player: OrderAndChaosPlayer,
 }
 
+impl OrderAndChaosPosition {
+    pub fn width(&self) -> usize {
+        self.board[0].len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.board.len()
+    }
+
+    fn has_no_space_left(&self) -> bool {
+        self.board.iter().flatten().all(|cell| cell.is_some())
+    }
+}
+
 impl PositionKey for OrderAndChaosPosition {}
 
+impl ExportPosition for OrderAndChaosPosition {
+    fn board_string(&self) -> String {
+        board_string(self.board.iter().map(|row| &row[..]))
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        Some(format!("{:?}", self.player))
+    }
+}
+
+impl Tier for OrderAndChaosPosition {
+    fn tier(&self) -> u32 {
+        self.board
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_some())
+            .count() as u32
+    }
+}
+
 impl Position<OrderAndChaosMove, OrderAndChaosPrimitiveValue> for OrderAndChaosPosition {
     fn do_move(&self, mov: OrderAndChaosMove) -> OrderAndChaosPosition {
         let mut board = self.board.clone();
@@ -39,6 +77,7 @@ impl Position<OrderAndChaosMove, OrderAndChaosPrimitiveValue> for OrderAndChaosP
         board[mov.x][mov.y] = Some(mov.piece);
 
         OrderAndChaosPosition {
+            k_in_a_row: self.k_in_a_row,
             board,
             player: self.player.next_player(),
         }
@@ -49,7 +88,7 @@ impl Position<OrderAndChaosMove, OrderAndChaosPrimitiveValue> for OrderAndChaosP
 
         self.board.iter().enumerate().for_each(|(i, line)| {
             line.iter().enumerate().for_each(|(j, cell)| {
-                if let None = cell {
+                if cell.is_none() {
                     moves.push(OrderAndChaosMove {
                         piece: OrderAndChaosPiece::X,
                         x: i,
@@ -70,19 +109,19 @@ impl Position<OrderAndChaosMove, OrderAndChaosPrimitiveValue> for OrderAndChaosP
     fn primitive_value(&self) -> OrderAndChaosPrimitiveValue {
         let is_order = self.player == OrderAndChaosPlayer::Order;
 
-        if has_k_in_a_row(&self.board, OrderAndChaosPiece::X) {
+        if has_k_in_a_row(&self.board, self.k_in_a_row, &OrderAndChaosPiece::X) {
             return match is_order {
                 true => OrderAndChaosPrimitiveValue::Win,
                 false => OrderAndChaosPrimitiveValue::Lose,
             };
         }
-        if has_k_in_a_row(&self.board, OrderAndChaosPiece::O) {
+        if has_k_in_a_row(&self.board, self.k_in_a_row, &OrderAndChaosPiece::O) {
             return match is_order {
                 true => OrderAndChaosPrimitiveValue::Win,
                 false => OrderAndChaosPrimitiveValue::Lose,
             };
         }
-        if has_no_space_left(&self.board) {
+        if self.has_no_space_left() {
             return match is_order {
                 true => OrderAndChaosPrimitiveValue::Lose,
                 false => OrderAndChaosPrimitiveValue::Win,
@@ -92,43 +131,3 @@ impl Position<OrderAndChaosMove, OrderAndChaosPrimitiveValue> for OrderAndChaosP
         OrderAndChaosPrimitiveValue::NotPrimitive
     }
 }
-
-fn has_k_in_a_row(
-    board: &[[Option<OrderAndChaosPiece>; WIDTH]; HEIGHT],
-    piece: OrderAndChaosPiece,
-) -> bool {
-    if (0..=(HEIGHT as i32 - K_IN_A_ROW as i32)).any(|offset| {
-        (0..WIDTH).any(|i| (0..K_IN_A_ROW).all(|j| board[j + offset as usize][i] == Some(piece)))
-    }) {
-        return true;
-    }
-
-    if (0..=(WIDTH as i32 - K_IN_A_ROW as i32)).any(|offset| {
-        (0..HEIGHT).any(|j| (0..K_IN_A_ROW).all(|i| board[j][i + offset as usize] == Some(piece)))
-    }) {
-        return true;
-    }
-
-    if (0..=(HEIGHT as i32 - K_IN_A_ROW as i32)).any(|j_offset| {
-        (0..=(WIDTH as i32 - K_IN_A_ROW as i32)).any(|i_offset| {
-            (0..K_IN_A_ROW)
-                .all(|x| board[x + j_offset as usize][x + i_offset as usize] == Some(piece))
-                || (0..K_IN_A_ROW).all(|x| {
-                    board[x + j_offset as usize][K_IN_A_ROW - 1 - x + i_offset as usize]
-                        == Some(piece)
-                })
-        })
-    }) {
-        return true;
-    }
-
-    false
-}
-
-fn has_no_space_left(board: &[[Option<OrderAndChaosPiece>; WIDTH]; HEIGHT]) -> bool {
-    if (0..WIDTH).all(|i| (0..HEIGHT).all(|j| board[j][i] != None)) {
-        return true;
-    }
-
-    false
-}
@@ -0,0 +1,50 @@
+use crate::games::order_and_chaos::OrderAndChaosPiece;
+use crate::games::symmetric::{BoardSymmetry, ColorSwap, SymmetricPosition, Symmetry};
+
+use super::OrderAndChaosPosition;
+
+/// Like `OrderAndChaosPositionSymEq`, but X and O are also interchangeable,
+/// since both pieces count the same towards k in a row.
+pub type OrderAndChaosPositionColorSymEq =
+    SymmetricPosition<OrderAndChaosPosition, ColorSwap<BoardSymmetry>>;
+
+impl<G> Symmetry<ColorSwap<G>> for OrderAndChaosPosition
+where
+    OrderAndChaosPosition: Symmetry<G>,
+{
+    fn transforms(&self) -> Vec<Self> {
+        let transforms = Symmetry::<G>::transforms(self);
+        let swapped: Vec<_> = transforms.iter().map(|p| p.swap_colors()).collect();
+
+        transforms.into_iter().chain(swapped).collect()
+    }
+
+    fn applies(&self) -> bool {
+        Symmetry::<G>::applies(self)
+    }
+}
+
+impl OrderAndChaosPosition {
+    fn swap_colors(&self) -> Self {
+        let board = self
+            .board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| {
+                        cell.map(|piece| match piece {
+                            OrderAndChaosPiece::X => OrderAndChaosPiece::O,
+                            OrderAndChaosPiece::O => OrderAndChaosPiece::X,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        OrderAndChaosPosition {
+            k_in_a_row: self.k_in_a_row,
+            board,
+            player: self.player,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::games::board;
+use crate::games::symmetric::{BoardSymmetry, Grid, KleinFour, SquareGrid, SymmetricPosition, D4};
+
+use super::OrderAndChaosPosition;
+
+/// Only valid for square boards, which `new` enforces.
+pub type OrderAndChaosPositionD4Eq = SymmetricPosition<OrderAndChaosPosition, D4>;
+
+pub type OrderAndChaosPositionVEq = SymmetricPosition<OrderAndChaosPosition, KleinFour>;
+
+/// The largest symmetry group valid for the board's shape.
+pub type OrderAndChaosPositionSymEq = SymmetricPosition<OrderAndChaosPosition, BoardSymmetry>;
+
+impl Grid for OrderAndChaosPosition {
+    fn width(&self) -> usize {
+        OrderAndChaosPosition::width(self)
+    }
+
+    fn height(&self) -> usize {
+        OrderAndChaosPosition::height(self)
+    }
+
+    fn reflect_along_x(&self) -> Self {
+        OrderAndChaosPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: board::reflect_along_x(&self.board),
+            player: self.player,
+        }
+    }
+
+    fn reflect_along_y(&self) -> Self {
+        OrderAndChaosPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: board::reflect_along_y(&self.board),
+            player: self.player,
+        }
+    }
+}
+
+impl SquareGrid for OrderAndChaosPosition {
+    fn transpose(&self) -> Self {
+        OrderAndChaosPosition {
+            k_in_a_row: self.k_in_a_row,
+            board: board::transpose(&self.board),
+            player: self.player,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+mod recursive_value;
+
+use std::sync::Arc;
+
+use crate::export::ExportPosition;
+use crate::solver::{PlayerMove, Position, PositionKey, PrimitiveValue, Tier};
+
+#[derive(Debug)]
+pub enum SubtractionPrimitiveValue {
+    _Win,
+    Lose,
+    _Tie,
+    NotPrimitive,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SubtractionMove {
+    pub amount: u32,
+}
+
+/// A subtraction game: players take turns removing any amount in the
+/// subtraction set, and whoever cannot move loses.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct SubtractionGame {
+    subtraction_set: Arc<[u32]>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct SubtractionPosition {
+    pub subtraction_set: Arc<[u32]>,
+    pub remaining_count: u32,
+}
+
+impl SubtractionGame {
+    /// Amounts are sorted and deduplicated, so moves are generated smallest
+    /// amount first.
+    pub fn new(subtraction_set: &[u32]) -> SubtractionGame {
+        assert!(
+            !subtraction_set.contains(&0),
+            "Subtraction set should not contain 0"
+        );
+
+        let mut subtraction_set = subtraction_set.to_vec();
+
+        subtraction_set.sort();
+        subtraction_set.dedup();
+
+        SubtractionGame {
+            subtraction_set: subtraction_set.into(),
+        }
+    }
+
+    pub fn subtraction_set(&self) -> &[u32] {
+        &self.subtraction_set
+    }
+
+    pub fn position(&self, remaining_count: u32) -> SubtractionPosition {
+        SubtractionPosition {
+            subtraction_set: self.subtraction_set.clone(),
+            remaining_count,
+        }
+    }
+}
+
+impl PlayerMove for SubtractionMove {}
+
+impl PrimitiveValue for SubtractionPrimitiveValue {
+    fn is_primitive(&self) -> bool {
+        !matches!(self, SubtractionPrimitiveValue::NotPrimitive)
+    }
+}
+
+impl PositionKey for SubtractionPosition {}
+
+/// The remaining count, then the subtraction set, e.g. `"7 (1/3/4)"`, so
+/// positions of different games solved together stay apart.
+impl ExportPosition for SubtractionPosition {
+    fn board_string(&self) -> String {
+        let subtraction_set = self
+            .subtraction_set
+            .iter()
+            .map(|amount| amount.to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        format!("{} ({subtraction_set})", self.remaining_count)
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        None
+    }
+}
+
+impl Tier for SubtractionPosition {
+    fn tier(&self) -> u32 {
+        self.remaining_count
+    }
+}
+
+impl Position<SubtractionMove, SubtractionPrimitiveValue> for SubtractionPosition {
+    fn do_move(&self, mov: SubtractionMove) -> SubtractionPosition {
+        SubtractionPosition {
+            subtraction_set: self.subtraction_set.clone(),
+            remaining_count: self.remaining_count - mov.amount,
+        }
+    }
+
+    fn generate_moves(&self) -> Vec<SubtractionMove> {
+        self.subtraction_set
+            .iter()
+            .filter(|&&amount| amount <= self.remaining_count)
+            .map(|&amount| SubtractionMove { amount })
+            .collect()
+    }
+
+    /// Without 1 in the set, counts below its smallest amount have no moves
+    /// either, not just 0.
+    fn primitive_value(&self) -> SubtractionPrimitiveValue {
+        match self
+            .subtraction_set
+            .iter()
+            .all(|&amount| amount > self.remaining_count)
+        {
+            true => SubtractionPrimitiveValue::Lose,
+            false => SubtractionPrimitiveValue::NotPrimitive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::SubtractionGame;
+    use crate::export::ExportPosition;
+    use crate::solver::{GameResult, GameResultWithRmt, Position, PrimitiveValue, RmtU32, Solver};
+
+    #[test]
+    fn take_1_to_k() {
+        for k in 1..=6 {
+            let game = SubtractionGame::new(&(1..=k).collect::<Vec<_>>());
+            let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+            for i in 0..=50 {
+                let result = solver.solve(game.position(i));
+
+                match i % (k + 1) {
+                    0 => assert_eq!(result, GameResult::Lose),
+                    _ => assert_eq!(result, GameResult::Win),
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn games_share_one_solver() {
+        let take_1_2 = SubtractionGame::new(&[2, 1, 2]);
+        let take_1_3_4 = SubtractionGame::new(&[1, 3, 4]);
+
+        assert_eq!(take_1_2.subtraction_set(), &[1, 2]);
+
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(solver.solve(take_1_2.position(7)), GameResult::Win);
+        assert_eq!(solver.solve(take_1_3_4.position(7)), GameResult::Lose);
+        assert_eq!(solver.len(), 8 + 8);
+
+        assert_eq!(take_1_2.position(7).board_string(), "7 (1/2)");
+        assert_eq!(take_1_3_4.position(7).board_string(), "7 (1/3/4)");
+    }
+
+    #[test]
+    fn set_without_1() {
+        let game = SubtractionGame::new(&[2, 5]);
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert!(game.position(1).primitive_value().is_primitive());
+        assert!(!game.position(2).primitive_value().is_primitive());
+
+        let results: Vec<_> = (0..=8).map(|i| solver.solve(game.position(i))).collect();
+
+        // Losing counts are 0, 1 and 4 mod 7.
+        for (i, result) in results.iter().enumerate() {
+            match i % 7 {
+                0 | 1 | 4 => assert_eq!(result.game_result, GameResult::Lose),
+                _ => assert_eq!(result.game_result, GameResult::Win),
+            };
+        }
+
+        assert_eq!(
+            results.iter().map(|result| result.rmt).collect::<Vec<_>>(),
+            [0, 0, 1, 1, 2, 1, 1, 2, 2].map(RmtU32::Val)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Subtraction set should not contain 0")]
+    fn zero_is_rejected() {
+        SubtractionGame::new(&[0, 1]);
+    }
+}
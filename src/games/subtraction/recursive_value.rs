@@ -0,0 +1,3 @@
+mod game_result;
+mod game_result_with_rmt;
+mod nimber;
@@ -0,0 +1,13 @@
+use crate::games::subtraction::SubtractionPrimitiveValue;
+use crate::solver::{GameResult, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResult> for SubtractionPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResult> {
+        match self {
+            SubtractionPrimitiveValue::_Win => Some(GameResult::Win),
+            SubtractionPrimitiveValue::Lose => Some(GameResult::Lose),
+            SubtractionPrimitiveValue::_Tie => Some(GameResult::Tie),
+            SubtractionPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::games::subtraction::SubtractionPrimitiveValue;
+use crate::solver::{GameResult, GameResultWithRmt, RmtU32, ToRecursiveValue};
+
+impl ToRecursiveValue<GameResultWithRmt> for SubtractionPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<GameResultWithRmt> {
+        match self {
+            SubtractionPrimitiveValue::_Win => Some(GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(0),
+            }),
+            SubtractionPrimitiveValue::Lose => Some(GameResultWithRmt {
+                game_result: GameResult::Lose,
+                rmt: RmtU32::Val(0),
+            }),
+            SubtractionPrimitiveValue::_Tie => Some(GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(0),
+            }),
+            SubtractionPrimitiveValue::NotPrimitive => None,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::games::subtraction::SubtractionPrimitiveValue;
+use crate::solver::{Nimber, ToRecursiveValue};
+
+impl ToRecursiveValue<Nimber> for SubtractionPrimitiveValue {
+    fn to_recursive_value(&self) -> Option<Nimber> {
+        match self {
+            SubtractionPrimitiveValue::Lose => Some(Nimber(0)),
+            SubtractionPrimitiveValue::NotPrimitive => None,
+            _ => panic!("Nimbers are only defined for normal play"),
+        }
+    }
+}
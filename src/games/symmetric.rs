@@ -0,0 +1,378 @@
+use std::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::database::DatabasePosition;
+use crate::export::ExportPosition;
+use crate::solver::{PlayerMove, Position, PositionHash, PositionKey, PrimitiveValue, Tier};
+
+/// A group `G` of transforms that map positions of a game to equivalent
+/// positions.
+pub trait Symmetry<G>: Sized {
+    /// The position under every transform of the group, identity included.
+    /// Transforms that give the same position may be listed once.
+    fn transforms(&self) -> Vec<Self>;
+
+    /// Whether the group is a symmetry of this position at all.
+    fn applies(&self) -> bool {
+        true
+    }
+
+    /// The smallest transform by `Ord`. Worth overriding for large groups
+    /// whose smallest member can be built directly.
+    fn canonical(&self) -> Self
+    where
+        Self: Ord,
+    {
+        self.transforms()
+            .into_iter()
+            .min()
+            .expect("transforms should not be empty")
+    }
+}
+
+/// Rectangular boards. Implementing this is enough to get [`KleinFour`].
+pub trait Grid: Clone {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    /// Reverses the order of the rows.
+    fn reflect_along_x(&self) -> Self;
+
+    /// Reverses every row.
+    fn reflect_along_y(&self) -> Self;
+}
+
+/// Boards that can be square. Implementing this as well as [`Grid`] is
+/// enough to get [`D4`] and [`BoardSymmetry`].
+pub trait SquareGrid: Grid {
+    /// Only called on square boards.
+    fn transpose(&self) -> Self;
+}
+
+/// Names a symmetry group, e.g. in database headers.
+pub trait SymmetryGroup {
+    fn name() -> String;
+}
+
+/// The dihedral group of the square. Only applies to square boards.
+#[derive(Debug, Clone, Copy)]
+pub struct D4;
+
+/// Reflections along both axes.
+#[derive(Debug, Clone, Copy)]
+pub struct KleinFour;
+
+/// [`D4`] for square boards, [`KleinFour`] otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardSymmetry;
+
+/// Left-right reflection.
+#[derive(Debug, Clone, Copy)]
+pub struct Mirror;
+
+/// Every reordering of a list, e.g. of Nim heaps.
+#[derive(Debug, Clone, Copy)]
+pub struct Permutation;
+
+/// `G` combined with swapping the two piece colours.
+#[derive(Debug, Clone, Copy)]
+pub struct ColorSwap<G>(PhantomData<G>);
+
+impl SymmetryGroup for D4 {
+    fn name() -> String {
+        "d4".to_string()
+    }
+}
+
+impl SymmetryGroup for KleinFour {
+    fn name() -> String {
+        "klein_four".to_string()
+    }
+}
+
+impl SymmetryGroup for BoardSymmetry {
+    fn name() -> String {
+        "board_symmetry".to_string()
+    }
+}
+
+impl SymmetryGroup for Mirror {
+    fn name() -> String {
+        "mirror".to_string()
+    }
+}
+
+impl SymmetryGroup for Permutation {
+    fn name() -> String {
+        "permutation".to_string()
+    }
+}
+
+impl<G: SymmetryGroup> SymmetryGroup for ColorSwap<G> {
+    fn name() -> String {
+        format!("color_swap({})", G::name())
+    }
+}
+
+impl<P: Grid> Symmetry<KleinFour> for P {
+    fn transforms(&self) -> Vec<Self> {
+        let v1 = self.reflect_along_x();
+        let v2 = self.reflect_along_y();
+        let v3 = v1.reflect_along_y();
+
+        vec![self.clone(), v1, v2, v3]
+    }
+}
+
+impl<P: SquareGrid> Symmetry<D4> for P {
+    fn transforms(&self) -> Vec<Self> {
+        let v = Symmetry::<KleinFour>::transforms(self);
+        let transposed: Vec<_> = v.iter().map(|p| p.transpose()).collect();
+
+        v.into_iter().chain(transposed).collect()
+    }
+
+    fn applies(&self) -> bool {
+        self.width() == self.height()
+    }
+}
+
+impl<P: SquareGrid> Symmetry<BoardSymmetry> for P {
+    fn transforms(&self) -> Vec<Self> {
+        match Symmetry::<D4>::applies(self) {
+            true => Symmetry::<D4>::transforms(self),
+            false => Symmetry::<KleinFour>::transforms(self),
+        }
+    }
+}
+
+/// Positions related by a transform of `G` are equal. The lexicographically
+/// smallest member of the orbit is computed once and used for hashing and
+/// equality; the original position is kept so moves stay in its coordinates.
+#[derive(Debug, Clone)]
+pub struct SymmetricPosition<P, G> {
+    position: P,
+    canonical: P,
+    group: PhantomData<G>,
+}
+
+impl<P: Symmetry<G> + Ord, G> SymmetricPosition<P, G> {
+    pub fn new(position: P) -> SymmetricPosition<P, G> {
+        assert!(
+            position.applies(),
+            "Symmetry group should apply to the position"
+        );
+
+        let canonical = position.canonical();
+
+        SymmetricPosition {
+            position,
+            canonical,
+            group: PhantomData,
+        }
+    }
+}
+
+impl<P, G> SymmetricPosition<P, G> {
+    pub fn position(&self) -> &P {
+        &self.position
+    }
+
+    /// The smallest position, by `Ord`, among all transforms of `position`.
+    pub fn canonical(&self) -> &P {
+        &self.canonical
+    }
+
+    pub fn into_position(self) -> P {
+        self.position
+    }
+}
+
+impl<P: Eq, G> PartialEq for SymmetricPosition<P, G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical == other.canonical
+    }
+}
+
+impl<P: Eq, G> Eq for SymmetricPosition<P, G> {}
+
+impl<P: Hash, G> Hash for SymmetricPosition<P, G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical.hash(state);
+    }
+}
+
+impl<P: Eq + Hash, G> PositionKey for SymmetricPosition<P, G> {}
+
+/// Indexes the canonical position, so every orbit gets a single index.
+impl<P: PositionHash + Symmetry<G> + Ord, G> PositionHash for SymmetricPosition<P, G> {
+    type Variant = P::Variant;
+
+    fn variant(&self) -> P::Variant {
+        self.canonical.variant()
+    }
+
+    fn index_count(variant: &P::Variant) -> u64 {
+        P::index_count(variant)
+    }
+
+    fn to_index(&self) -> u64 {
+        self.canonical.to_index()
+    }
+
+    fn from_index(variant: &P::Variant, index: u64) -> Self {
+        SymmetricPosition::new(P::from_index(variant, index))
+    }
+}
+
+/// Only canonical positions are stored, so the group is part of the game id.
+impl<P, G> DatabasePosition for SymmetricPosition<P, G>
+where
+    P: DatabasePosition + Symmetry<G> + Ord,
+    G: SymmetryGroup,
+{
+    fn game_id() -> String {
+        format!("{}/{}", P::game_id(), G::name())
+    }
+
+    fn variant_params(variant: &P::Variant) -> Vec<u64> {
+        P::variant_params(variant)
+    }
+}
+
+/// Describes the position as it was reached, not its canonical form.
+impl<P: ExportPosition, G> ExportPosition for SymmetricPosition<P, G> {
+    fn board_string(&self) -> String {
+        self.position.board_string()
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        self.position.player_to_move()
+    }
+}
+
+impl<P: Tier, G> Tier for SymmetricPosition<P, G> {
+    fn tier(&self) -> u32 {
+        self.position.tier()
+    }
+}
+
+impl<P, G, M, PV> Position<M, PV> for SymmetricPosition<P, G>
+where
+    P: Position<M, PV> + Symmetry<G> + Ord,
+    M: PlayerMove,
+    PV: PrimitiveValue,
+{
+    fn do_move(&self, mov: M) -> SymmetricPosition<P, G> {
+        SymmetricPosition::new(self.position.do_move(mov))
+    }
+
+    fn generate_moves(&self) -> Vec<M> {
+        self.position.generate_moves()
+    }
+
+    fn primitive_value(&self) -> PV {
+        self.position.primitive_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::{BoardSymmetry, ColorSwap, Symmetry, D4};
+    use crate::games::mnk::{MnkPlayer, MnkPosition};
+    use crate::games::order_and_chaos::{OrderAndChaosGame, OrderAndChaosPositionColorSymEq};
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::solver::{GameResultWithRmt, Solver};
+
+    fn asymmetric(width: usize, height: usize) -> MnkPosition {
+        let mut position = MnkPosition::start(width, height, 3);
+
+        position.board[0][0] = Some(MnkPlayer::X);
+        position.board[0][1] = Some(MnkPlayer::O);
+
+        position
+    }
+
+    #[test]
+    fn transforms_are_distinct() {
+        for (width, height, group_order) in [(3, 3, 8), (4, 3, 4), (2, 5, 4)] {
+            let transforms = Symmetry::<BoardSymmetry>::transforms(&asymmetric(width, height));
+
+            assert_eq!(transforms.len(), group_order);
+            assert_eq!(transforms.iter().collect::<HashSet<_>>().len(), group_order);
+        }
+    }
+
+    #[test]
+    fn d4_applies_to_square_boards_only() {
+        assert!(Symmetry::<D4>::applies(&asymmetric(3, 3)));
+        assert!(!Symmetry::<D4>::applies(&asymmetric(4, 3)));
+    }
+
+    #[test]
+    fn every_orbit_is_one_entry_tic_tac_toe() {
+        let start = TicTacToePosition::start();
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut d4_eq_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        solver.solve(start.clone());
+        d4_eq_solver.solve(TicTacToePositionD4Eq::new(start));
+
+        let orbits: HashSet<_> = solver
+            .iter()
+            .map(|(position, _)| {
+                TicTacToePositionD4Eq::new(position.clone())
+                    .canonical()
+                    .clone()
+            })
+            .collect();
+
+        assert_eq!(orbits.len(), d4_eq_solver.len());
+
+        for (position, value) in &solver {
+            for transform in Symmetry::<D4>::transforms(position) {
+                assert_eq!(
+                    d4_eq_solver.get(&TicTacToePositionD4Eq::new(transform)),
+                    Some(value)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_orbit_is_one_entry_order_and_chaos() {
+        let game = OrderAndChaosGame::new(3, 2, 2);
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut color_sym_eq_solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        solver.solve(game.start());
+        color_sym_eq_solver.solve(game.start_color_sym_eq());
+
+        let orbits: HashSet<_> = solver
+            .iter()
+            .map(|(position, _)| {
+                OrderAndChaosPositionColorSymEq::new(position.clone())
+                    .canonical()
+                    .clone()
+            })
+            .collect();
+
+        assert_eq!(orbits.len(), color_sym_eq_solver.len());
+
+        for (position, value) in &solver {
+            for transform in Symmetry::<ColorSwap<BoardSymmetry>>::transforms(position) {
+                assert_eq!(
+                    color_sym_eq_solver.get(&OrderAndChaosPositionColorSymEq::new(transform)),
+                    Some(value)
+                );
+            }
+        }
+    }
+}
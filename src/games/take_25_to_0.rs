@@ -1,96 +1,31 @@
-use crate::solver::{
-    GameResult, PlayerMove, Position, PositionKey, PrimitiveValue, ToRecursiveValue,
-};
+use super::subtraction::{SubtractionGame, SubtractionPosition};
 
-#[derive(Debug)]
-enum TwentyFiveToZeroPrimitiveValue {
-    _Win,
-    Lose,
-    _Tie,
-    NotPrimitive,
-}
-
-#[derive(Debug)]
-enum TwentyFiveToZeroMove {
-    Take1,
-    Take3,
-    Take4,
-}
-
-#[derive(Debug, PartialEq, Eq, Hash)]
-struct TwentyFiveToZeroPosition {
-    remaining_count: u32,
-}
+pub const SUBTRACTION_SET: [u32; 3] = [1, 3, 4];
 
-impl PlayerMove for TwentyFiveToZeroMove {}
+pub type TwentyFiveToZeroPosition = SubtractionPosition;
 
-impl PrimitiveValue for TwentyFiveToZeroPrimitiveValue {
-    fn is_primitive(&self) -> bool {
-        match self {
-            TwentyFiveToZeroPrimitiveValue::NotPrimitive => false,
-            _ => true,
-        }
-    }
-}
-
-impl ToRecursiveValue<GameResult> for TwentyFiveToZeroPrimitiveValue {
-    fn to_recursive_value(&self) -> Option<GameResult> {
-        match self {
-            TwentyFiveToZeroPrimitiveValue::_Win => Some(GameResult::Win),
-            TwentyFiveToZeroPrimitiveValue::Lose => Some(GameResult::Lose),
-            TwentyFiveToZeroPrimitiveValue::_Tie => Some(GameResult::Tie),
-            TwentyFiveToZeroPrimitiveValue::NotPrimitive => None,
-        }
-    }
+pub fn game() -> SubtractionGame {
+    SubtractionGame::new(&SUBTRACTION_SET)
 }
 
-impl PositionKey for TwentyFiveToZeroPosition {}
-
-impl Position<TwentyFiveToZeroMove, TwentyFiveToZeroPrimitiveValue> for TwentyFiveToZeroPosition {
-    fn do_move(&self, mov: TwentyFiveToZeroMove) -> TwentyFiveToZeroPosition {
-        let remaining_count = match mov {
-            TwentyFiveToZeroMove::Take1 => self.remaining_count - 1,
-            TwentyFiveToZeroMove::Take3 => self.remaining_count - 3,
-            TwentyFiveToZeroMove::Take4 => self.remaining_count - 4,
-        };
-
-        TwentyFiveToZeroPosition { remaining_count }
-    }
-
-    fn generate_moves(&self) -> Vec<TwentyFiveToZeroMove> {
-        match self.remaining_count {
-            4.. => vec![
-                TwentyFiveToZeroMove::Take1,
-                TwentyFiveToZeroMove::Take3,
-                TwentyFiveToZeroMove::Take4,
-            ],
-            3 => vec![TwentyFiveToZeroMove::Take1, TwentyFiveToZeroMove::Take3],
-            1..=2 => vec![TwentyFiveToZeroMove::Take1],
-            _ => vec![],
-        }
-    }
-
-    fn primitive_value(&self) -> TwentyFiveToZeroPrimitiveValue {
-        match self.remaining_count {
-            0 => TwentyFiveToZeroPrimitiveValue::Lose,
-            _ => TwentyFiveToZeroPrimitiveValue::NotPrimitive,
-        }
-    }
+pub fn position(remaining_count: u32) -> TwentyFiveToZeroPosition {
+    game().position(remaining_count)
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use super::TwentyFiveToZeroPosition;
+    use super::game;
     use crate::solver::{GameResult, Solver};
 
     #[test]
     fn it_works() {
-        let mut solver = Solver::new(HashMap::new());
+        let game = game();
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
 
         for i in (0..=25).rev() {
-            let result = solver.solve(TwentyFiveToZeroPosition { remaining_count: i });
+            let result = solver.solve(game.position(i));
 
             match i % 7 {
                 0 => assert_eq!(result, GameResult::Lose),
@@ -17,7 +17,7 @@ pub enum TicTacToePrimitiveValue {
     NotPrimitive,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TicTacToeMove {
     x: usize,
     y: usize,
@@ -27,10 +27,7 @@ impl PlayerMove for TicTacToeMove {}
 
 impl PrimitiveValue for TicTacToePrimitiveValue {
     fn is_primitive(&self) -> bool {
-        match self {
-            TicTacToePrimitiveValue::NotPrimitive => false,
-            _ => true,
-        }
+        !matches!(self, TicTacToePrimitiveValue::NotPrimitive)
     }
 }
 
@@ -38,17 +35,14 @@ impl PrimitiveValue for TicTacToePrimitiveValue {
 mod tests {
     use std::collections::HashMap;
 
-    use super::{TicTacToePlayer, TicTacToePosition};
+    use super::TicTacToePosition;
     use crate::solver::{GameResult, Solver};
 
     #[test]
     fn it_works() {
         let mut solver = Solver::new(HashMap::new());
 
-        let result: GameResult = solver.solve(TicTacToePosition {
-            board: [[None, None, None], [None, None, None], [None, None, None]],
-            player: TicTacToePlayer::X,
-        });
+        let result: GameResult = solver.solve(TicTacToePosition::start());
 
         assert_eq!(result, GameResult::Tie);
     }
@@ -1,8 +1,11 @@
-use crate::solver::{Position, PositionKey};
+use crate::export::ExportPosition;
+use crate::games::board::board_string;
+use crate::solver::{Position, PositionKey, Tier};
 
 use super::{TicTacToeMove, TicTacToePrimitiveValue, LENGTH};
 
 mod position_d4_eq;
+mod position_hash;
 
 pub use position_d4_eq::TicTacToePositionD4Eq;
 
@@ -27,11 +30,41 @@ pub struct TicTacToePosition {
     pub player: TicTacToePlayer,
 }
 
+impl TicTacToePosition {
+    /// The empty board, with X to move.
+    pub fn start() -> TicTacToePosition {
+        TicTacToePosition {
+            board: [[None; LENGTH]; LENGTH],
+            player: TicTacToePlayer::X,
+        }
+    }
+}
+
 impl PositionKey for TicTacToePosition {}
 
+impl ExportPosition for TicTacToePosition {
+    fn board_string(&self) -> String {
+        board_string(self.board.iter().map(|row| &row[..]))
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        Some(format!("{:?}", self.player))
+    }
+}
+
+impl Tier for TicTacToePosition {
+    fn tier(&self) -> u32 {
+        self.board
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_some())
+            .count() as u32
+    }
+}
+
 impl Position<TicTacToeMove, TicTacToePrimitiveValue> for TicTacToePosition {
     fn do_move(&self, mov: TicTacToeMove) -> TicTacToePosition {
-        let mut board = self.board.clone();
+        let mut board = self.board;
 
         board[mov.x][mov.y] = Some(self.player);
 
@@ -46,7 +79,7 @@ impl Position<TicTacToeMove, TicTacToePrimitiveValue> for TicTacToePosition {
 
         self.board.iter().enumerate().for_each(|(i, line)| {
             line.iter().enumerate().for_each(|(j, cell)| {
-                if let None = cell {
+                if cell.is_none() {
                     moves.push(TicTacToeMove { x: i, y: j });
                 }
             })
@@ -74,7 +107,7 @@ impl Position<TicTacToeMove, TicTacToePrimitiveValue> for TicTacToePosition {
             return TicTacToePrimitiveValue::Lose;
         }
 
-        if (0..LENGTH).any(|i| (0..LENGTH).any(|j| self.board[i][j] == None)) {
+        if (0..LENGTH).any(|i| (0..LENGTH).any(|j| self.board[i][j].is_none())) {
             return TicTacToePrimitiveValue::NotPrimitive;
         }
 
@@ -0,0 +1,62 @@
+use crate::database::DatabasePosition;
+use crate::games::board::{base_3_index_count, rank_base_3, unrank_base_3};
+use crate::games::tic_tac_toe::LENGTH;
+use crate::solver::PositionHash;
+
+use super::{TicTacToePlayer, TicTacToePosition};
+
+impl PositionHash for TicTacToePosition {
+    type Variant = ();
+
+    fn variant(&self) {}
+
+    fn index_count(_: &()) -> u64 {
+        base_3_index_count(LENGTH * LENGTH)
+    }
+
+    fn to_index(&self) -> u64 {
+        let digits = self.board.iter().flatten().map(|cell| match cell {
+            None => 0,
+            Some(TicTacToePlayer::X) => 1,
+            Some(TicTacToePlayer::O) => 2,
+        });
+
+        rank_base_3(digits)
+    }
+
+    fn from_index(_: &(), index: u64) -> Self {
+        let (digits, second_player) = unrank_base_3(index, LENGTH * LENGTH);
+
+        let mut board = [[None; LENGTH]; LENGTH];
+
+        board
+            .iter_mut()
+            .flatten()
+            .zip(digits)
+            .for_each(|(cell, digit)| {
+                *cell = match digit {
+                    0 => None,
+                    1 => Some(TicTacToePlayer::X),
+                    _ => Some(TicTacToePlayer::O),
+                }
+            });
+
+        TicTacToePosition {
+            board,
+            player: match second_player {
+                false => TicTacToePlayer::X,
+                true => TicTacToePlayer::O,
+            },
+        }
+    }
+}
+
+impl DatabasePosition for TicTacToePosition {
+    fn game_id() -> String {
+        "tic_tac_toe".to_string()
+    }
+
+    fn variant_params(_: &()) -> Vec<u64> {
+        vec![LENGTH as u64]
+    }
+}
@@ -26,14 +26,11 @@ pub enum TicTacToeNonSqPrimitiveValue {
 
 impl PrimitiveValue for TicTacToeNonSqPrimitiveValue {
     fn is_primitive(&self) -> bool {
-        match self {
-            TicTacToeNonSqPrimitiveValue::NotPrimitive => false,
-            _ => true,
-        }
+        !matches!(self, TicTacToeNonSqPrimitiveValue::NotPrimitive)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TicTacToeNonSqMove {
     x: usize,
     y: usize,
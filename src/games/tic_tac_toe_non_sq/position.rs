@@ -1,7 +1,10 @@
-use crate::solver::{Position, PositionKey};
+use crate::export::ExportPosition;
+use crate::games::board::board_string;
+use crate::solver::{Position, PositionKey, Tier};
 
 use super::{TicTacToeNonSqMove, TicTacToeNonSqPrimitiveValue, HEIGHT, K_IN_A_ROW, WIDTH};
 
+mod position_hash;
 mod position_v_eq;
 
 pub use position_v_eq::TicTacToeNonSqPositionVEq;
@@ -29,9 +32,29 @@ pub struct TicTacToeNonSqPosition {
 
 impl PositionKey for TicTacToeNonSqPosition {}
 
+impl ExportPosition for TicTacToeNonSqPosition {
+    fn board_string(&self) -> String {
+        board_string(self.board.iter().map(|row| &row[..]))
+    }
+
+    fn player_to_move(&self) -> Option<String> {
+        Some(format!("{:?}", self.player))
+    }
+}
+
+impl Tier for TicTacToeNonSqPosition {
+    fn tier(&self) -> u32 {
+        self.board
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_some())
+            .count() as u32
+    }
+}
+
 impl Position<TicTacToeNonSqMove, TicTacToeNonSqPrimitiveValue> for TicTacToeNonSqPosition {
     fn do_move(&self, mov: TicTacToeNonSqMove) -> TicTacToeNonSqPosition {
-        let mut board = self.board.clone();
+        let mut board = self.board;
 
         board[mov.x][mov.y] = Some(self.player);
 
@@ -46,7 +69,7 @@ impl Position<TicTacToeNonSqMove, TicTacToeNonSqPrimitiveValue> for TicTacToeNon
 
         self.board.iter().enumerate().for_each(|(i, line)| {
             line.iter().enumerate().for_each(|(j, cell)| {
-                if let None = cell {
+                if cell.is_none() {
                     moves.push(TicTacToeNonSqMove { x: i, y: j });
                 }
             })
@@ -87,7 +110,7 @@ impl Position<TicTacToeNonSqMove, TicTacToeNonSqPrimitiveValue> for TicTacToeNon
             return TicTacToeNonSqPrimitiveValue::Lose;
         }
 
-        if (0..WIDTH).any(|i| (0..HEIGHT).any(|j| self.board[j][i] == None)) {
+        if (0..WIDTH).any(|i| (0..HEIGHT).any(|j| self.board[j][i].is_none())) {
             return TicTacToeNonSqPrimitiveValue::NotPrimitive;
         }
 
@@ -0,0 +1,62 @@
+use crate::database::DatabasePosition;
+use crate::games::board::{base_3_index_count, rank_base_3, unrank_base_3};
+use crate::games::tic_tac_toe_non_sq::{HEIGHT, K_IN_A_ROW, WIDTH};
+use crate::solver::PositionHash;
+
+use super::{TicTacToeNonSqPlayer, TicTacToeNonSqPosition};
+
+impl PositionHash for TicTacToeNonSqPosition {
+    type Variant = ();
+
+    fn variant(&self) {}
+
+    fn index_count(_: &()) -> u64 {
+        base_3_index_count(WIDTH * HEIGHT)
+    }
+
+    fn to_index(&self) -> u64 {
+        let digits = self.board.iter().flatten().map(|cell| match cell {
+            None => 0,
+            Some(TicTacToeNonSqPlayer::X) => 1,
+            Some(TicTacToeNonSqPlayer::O) => 2,
+        });
+
+        rank_base_3(digits)
+    }
+
+    fn from_index(_: &(), index: u64) -> Self {
+        let (digits, second_player) = unrank_base_3(index, WIDTH * HEIGHT);
+
+        let mut board = [[None; WIDTH]; HEIGHT];
+
+        board
+            .iter_mut()
+            .flatten()
+            .zip(digits)
+            .for_each(|(cell, digit)| {
+                *cell = match digit {
+                    0 => None,
+                    1 => Some(TicTacToeNonSqPlayer::X),
+                    _ => Some(TicTacToeNonSqPlayer::O),
+                }
+            });
+
+        TicTacToeNonSqPosition {
+            board,
+            player: match second_player {
+                false => TicTacToeNonSqPlayer::X,
+                true => TicTacToeNonSqPlayer::O,
+            },
+        }
+    }
+}
+
+impl DatabasePosition for TicTacToeNonSqPosition {
+    fn game_id() -> String {
+        "tic_tac_toe_non_sq".to_string()
+    }
+
+    fn variant_params(_: &()) -> Vec<u64> {
+        vec![WIDTH as u64, HEIGHT as u64, K_IN_A_ROW as u64]
+    }
+}
@@ -1,75 +1,21 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    hash::{Hash, Hasher},
-};
-
-use crate::{
-    games::tic_tac_toe_non_sq::{TicTacToeNonSqMove, TicTacToeNonSqPrimitiveValue},
-    solver::{Position, PositionKey},
-};
+use crate::games::symmetric::{Grid, KleinFour, SymmetricPosition};
+use crate::games::tic_tac_toe_non_sq::{HEIGHT, WIDTH};
 
 use super::TicTacToeNonSqPosition;
 
-pub struct TicTacToeNonSqPositionVEq {
-    pub position: TicTacToeNonSqPosition,
-}
-
-impl PositionKey for TicTacToeNonSqPositionVEq {}
-
-impl PartialEq for TicTacToeNonSqPositionVEq {
-    fn eq(&self, other: &Self) -> bool {
-        (self.position == other.position)
-            || (self.position.v1() == other.position)
-            || (self.position.v2() == other.position)
-            || (self.position.v3() == other.position)
-    }
-}
-
-impl Eq for TicTacToeNonSqPositionVEq {}
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct HashAndPosition(u64, TicTacToeNonSqPosition);
-
-impl Hash for TicTacToeNonSqPositionVEq {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let hashes = [
-            self.position.clone(),
-            self.position.v1(),
-            self.position.v2(),
-            self.position.v3(),
-        ]
-        .map(|p| {
-            let mut experiment_hasher = DefaultHasher::new();
-            p.hash(&mut experiment_hasher);
-            HashAndPosition(experiment_hasher.finish(), p)
-        });
+pub type TicTacToeNonSqPositionVEq = SymmetricPosition<TicTacToeNonSqPosition, KleinFour>;
 
-        let HashAndPosition(_, min_position) =
-            hashes.iter().min().expect("hashes should not be empty");
-
-        min_position.hash(state);
+impl Grid for TicTacToeNonSqPosition {
+    fn width(&self) -> usize {
+        WIDTH
     }
-}
 
-impl Position<TicTacToeNonSqMove, TicTacToeNonSqPrimitiveValue> for TicTacToeNonSqPositionVEq {
-    fn do_move(&self, mov: TicTacToeNonSqMove) -> TicTacToeNonSqPositionVEq {
-        TicTacToeNonSqPositionVEq {
-            position: self.position.do_move(mov),
-        }
+    fn height(&self) -> usize {
+        HEIGHT
     }
 
-    fn generate_moves(&self) -> Vec<TicTacToeNonSqMove> {
-        self.position.generate_moves()
-    }
-
-    fn primitive_value(&self) -> TicTacToeNonSqPrimitiveValue {
-        self.position.primitive_value()
-    }
-}
-
-impl TicTacToeNonSqPosition {
     fn reflect_along_x(&self) -> Self {
-        let mut board = self.board.clone();
+        let mut board = self.board;
 
         board.reverse();
 
@@ -80,7 +26,7 @@ impl TicTacToeNonSqPosition {
     }
 
     fn reflect_along_y(&self) -> Self {
-        let mut board = self.board.clone();
+        let mut board = self.board;
 
         board.iter_mut().for_each(|row| row.reverse());
 
@@ -89,16 +35,4 @@ impl TicTacToeNonSqPosition {
             player: self.player,
         }
     }
-
-    fn v1(&self) -> Self {
-        self.reflect_along_x()
-    }
-
-    fn v2(&self) -> Self {
-        self.reflect_along_y()
-    }
-
-    fn v3(&self) -> Self {
-        self.reflect_along_x().reflect_along_y()
-    }
 }
@@ -1,3 +1,9 @@
 pub mod solver;
 
 pub mod games;
+
+pub mod analysis;
+
+pub mod database;
+
+pub mod export;
@@ -1,46 +1,72 @@
+mod disjunctive_sum;
+mod iterative;
+mod memo_store;
+mod moves;
+mod parallel;
+mod perfect_play;
 mod player_move;
 mod position;
 mod primitive_value;
+mod query;
 mod recursive_value;
+mod retrograde;
+mod tiered;
+mod value_only;
 
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
+pub use self::memo_store::ArrayStore;
+pub use self::memo_store::MemoStore;
+pub use self::memo_store::PackedValue;
 pub use self::player_move::PlayerMove;
 pub use self::position::Position;
+pub use self::position::PositionHash;
 pub use self::position::PositionKey;
+pub use self::position::Tier;
 pub use self::primitive_value::PrimitiveValue;
+pub use self::recursive_value::FlipWinLose;
 pub use self::recursive_value::GameResult;
 pub use self::recursive_value::GameResultWithRmt;
+pub use self::recursive_value::LoopyRecursiveValue;
+pub use self::recursive_value::Nimber;
+pub use self::recursive_value::RecursiveValue;
+pub use self::recursive_value::Rmt;
 pub use self::recursive_value::RmtU32;
+pub use self::recursive_value::ToGameResult;
 pub use self::recursive_value::ToRecursiveValue;
+pub use self::recursive_value::ToRmt;
 
-use self::recursive_value::RecursiveValue;
-
+/// `S` is where solved values are kept: a `HashMap` by default, or e.g. an
+/// `ArrayStore` for games with a dense `PositionHash`.
 #[derive(Debug)]
-pub struct Solver<P, M, PV, RV>
+pub struct Solver<P, M, PV, RV, S = HashMap<P, RV>>
 where
     P: Position<M, PV> + PositionKey,
     M: PlayerMove,
     PV: PrimitiveValue + ToRecursiveValue<RV>,
     RV: RecursiveValue,
+    S: MemoStore<P, RV>,
 {
-    memoized_map: HashMap<P, RV>,
+    memoized_map: S,
+    partial_map: HashMap<P, RV>,
 
     _phantom_m: PhantomData<M>,
     _phantom_pv: PhantomData<PV>,
 }
 
-impl<P, M, PV, RV> Solver<P, M, PV, RV>
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
 where
     P: Position<M, PV> + PositionKey,
     M: PlayerMove,
     PV: PrimitiveValue + ToRecursiveValue<RV>,
     RV: RecursiveValue,
+    S: MemoStore<P, RV>,
 {
-    pub fn new(memoized_map: HashMap<P, RV>) -> Self {
+    pub fn new(memoized_map: S) -> Self {
         Self {
             memoized_map,
+            partial_map: HashMap::new(),
 
             _phantom_m: PhantomData,
             _phantom_pv: PhantomData,
@@ -55,6 +81,14 @@ where
             .collect()
     }
 
+    fn memoize(&mut self, position: P, result: RV) {
+        if !self.partial_map.is_empty() {
+            self.partial_map.remove(&position);
+        }
+
+        self.memoized_map.insert(position, result);
+    }
+
     fn solve_not_memoized(&mut self, position: &P) -> RV {
         if let Some(result) = position.primitive_value().to_recursive_value() {
             return result;
@@ -71,12 +105,12 @@ where
 
     pub fn solve(&mut self, position: P) -> RV {
         if let Some(result) = self.memoized_map.get(&position) {
-            return result.clone();
+            return result;
         }
 
         let result = self.solve_not_memoized(&position);
 
-        self.memoized_map.insert(position, result.clone());
+        self.memoize(position, result.clone());
 
         result
     }
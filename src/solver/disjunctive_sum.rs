@@ -0,0 +1,21 @@
+use super::{
+    MemoStore, Nimber, PlayerMove, Position, PositionKey, PrimitiveValue, Solver, ToRecursiveValue,
+};
+
+impl<P, M, PV, S> Solver<P, M, PV, Nimber, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<Nimber>,
+    S: MemoStore<P, Nimber>,
+{
+    /// Nimber of the disjunctive sum of `positions`, where each turn is a
+    /// move in exactly one component. Each component is solved on its own
+    /// and the nimbers are XOR-ed, so the product space is never searched.
+    pub fn solve_disjunctive_sum(&mut self, positions: impl IntoIterator<Item = P>) -> Nimber {
+        positions
+            .into_iter()
+            .map(|position| self.solve(position))
+            .fold(Nimber(0), |sum, nimber| sum ^ nimber)
+    }
+}
@@ -0,0 +1,190 @@
+use super::{
+    MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue, Solver,
+    ToRecursiveValue,
+};
+
+struct Frame<P, RV> {
+    position: P,
+    children: Vec<P>,
+    children_results: Vec<RV>,
+}
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+    S: MemoStore<P, RV>,
+{
+    fn frame(&self, position: P) -> Frame<P, RV> {
+        // Reversed so that popping visits children in `generate_moves` order,
+        // the same order as the recursive solve.
+        let mut children = self.children(&position);
+        children.reverse();
+
+        Frame {
+            position,
+            children,
+            children_results: vec![],
+        }
+    }
+
+    /// Same as `solve`, but keeps the work stack on the heap instead of
+    /// recursing, so the depth of the game is only limited by memory.
+    pub fn solve_iterative(&mut self, position: P) -> RV {
+        if let Some(result) = self.memoized_map.get(&position) {
+            return result;
+        }
+
+        if let Some(result) = position.primitive_value().to_recursive_value() {
+            self.memoize(position, result.clone());
+            return result;
+        }
+
+        let mut stack = vec![self.frame(position)];
+
+        loop {
+            let frame = stack.last_mut().expect("stack should not be empty");
+
+            if let Some(child) = frame.children.pop() {
+                if let Some(result) = self.memoized_map.get(&child) {
+                    frame.children_results.push(result.clone());
+                    continue;
+                }
+
+                if let Some(result) = child.primitive_value().to_recursive_value() {
+                    frame.children_results.push(result.clone());
+                    self.memoize(child, result);
+                    continue;
+                }
+
+                let child_frame = self.frame(child);
+                stack.push(child_frame);
+                continue;
+            }
+
+            let Frame {
+                position,
+                children_results,
+                ..
+            } = stack.pop().expect("stack should not be empty");
+
+            let result = RV::recursion_step(&children_results);
+
+            self.memoize(position, result.clone());
+
+            match stack.last_mut() {
+                Some(parent) => parent.children_results.push(result),
+                None => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::order_and_chaos::{OrderAndChaosGame, OrderAndChaosPositionD4Eq};
+    use crate::games::take_10_to_0;
+    use crate::games::take_25_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::tic_tac_toe_non_sq::{
+        TicTacToeNonSqPlayer, TicTacToeNonSqPosition, TicTacToeNonSqPositionVEq,
+    };
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver};
+
+    #[test]
+    fn deep_game() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve_iterative(take_10_to_0::position(1_000_000));
+
+        assert_eq!(
+            result,
+            GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(666_667),
+            }
+        );
+        assert_eq!(1_000_001, solver.memoized_map.len());
+    }
+
+    #[test]
+    fn same_as_recursive_take_10_to_0() {
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut iterative = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        for i in (0..=100).rev() {
+            assert_eq!(
+                recursive.solve(take_10_to_0::position(i)),
+                iterative.solve_iterative(take_10_to_0::position(i)),
+            );
+        }
+
+        assert_eq!(recursive.memoized_map, iterative.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_take_25_to_0() {
+        let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut iterative = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        for i in 0..=25 {
+            assert_eq!(
+                recursive.solve(take_25_to_0::position(i)),
+                iterative.solve_iterative(take_25_to_0::position(i)),
+            );
+        }
+
+        assert_eq!(recursive.memoized_map, iterative.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_tic_tac_toe() {
+        let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut iterative = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(
+            recursive.solve(TicTacToePosition::start()),
+            iterative.solve_iterative(TicTacToePosition::start()),
+        );
+        assert_eq!(recursive.memoized_map, iterative.memoized_map);
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut iterative = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || TicTacToePositionD4Eq::new(TicTacToePosition::start());
+
+        assert_eq!(recursive.solve(start()), iterative.solve_iterative(start()));
+        assert_eq!(recursive.memoized_map, iterative.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_tic_tac_toe_non_sq() {
+        let start = || {
+            TicTacToeNonSqPositionVEq::new(TicTacToeNonSqPosition {
+                board: [[None; 4]; 3],
+                player: TicTacToeNonSqPlayer::X,
+            })
+        };
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut iterative = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(recursive.solve(start()), iterative.solve_iterative(start()));
+        assert_eq!(recursive.memoized_map, iterative.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_order_and_chaos() {
+        let start = || OrderAndChaosPositionD4Eq::new(OrderAndChaosGame::new(3, 3, 3).start());
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut iterative = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(recursive.solve(start()), iterative.solve_iterative(start()));
+        assert_eq!(recursive.memoized_map, iterative.memoized_map);
+    }
+}
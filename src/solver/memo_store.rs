@@ -0,0 +1,24 @@
+mod array_store;
+mod hash_map;
+mod packed_value;
+
+pub use self::array_store::ArrayStore;
+pub use self::packed_value::PackedValue;
+
+/// Where a `Solver` keeps the value of every solved position.
+pub trait MemoStore<P, RV> {
+    fn get(&self, position: &P) -> Option<RV>;
+
+    fn insert(&mut self, position: P, value: RV);
+
+    fn contains(&self, position: &P) -> bool;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calls `visit` on every stored entry, in no particular order.
+    fn for_each(&self, visit: impl FnMut(&P, &RV));
+}
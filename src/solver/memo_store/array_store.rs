@@ -0,0 +1,227 @@
+use std::marker::PhantomData;
+
+use super::{MemoStore, PackedValue};
+use crate::solver::PositionHash;
+
+/// A flat table indexed by `PositionHash::to_index`, holding `RV::BITS` bits
+/// per position plus one bit marking which positions are solved.
+///
+/// Takes memory for every index of the variant up front, whether reachable
+/// or not, so it only pays off for densely encoded games.
+#[derive(Debug)]
+pub struct ArrayStore<P: PositionHash, RV> {
+    variant: P::Variant,
+    solved: Vec<u64>,
+    values: Vec<u64>,
+    len: usize,
+
+    _phantom_rv: PhantomData<RV>,
+}
+
+impl<P, RV> ArrayStore<P, RV>
+where
+    P: PositionHash,
+    RV: PackedValue,
+{
+    pub fn new(variant: P::Variant) -> Self {
+        let (solved_words, value_words) = Self::word_counts(&variant);
+
+        ArrayStore {
+            variant,
+            solved: vec![0; solved_words],
+            values: vec![0; value_words],
+            len: 0,
+
+            _phantom_rv: PhantomData,
+        }
+    }
+
+    /// Lengths of the solved bitset and of the packed values, in words.
+    pub(crate) fn word_counts(variant: &P::Variant) -> (usize, usize) {
+        assert!(64 % RV::BITS == 0, "RV::BITS should divide 64");
+
+        let index_count =
+            usize::try_from(P::index_count(variant)).expect("Index count should fit in memory");
+
+        let values_per_word = (64 / RV::BITS) as usize;
+
+        (
+            index_count.div_ceil(64),
+            index_count.div_ceil(values_per_word),
+        )
+    }
+
+    pub fn variant(&self) -> &P::Variant {
+        &self.variant
+    }
+
+    /// The raw solved bitset and packed values, as laid out in memory.
+    pub(crate) fn words(&self) -> (&[u64], &[u64]) {
+        (&self.solved, &self.values)
+    }
+
+    /// The inverse of `words`. Returns `None` if the word counts do not
+    /// match the variant.
+    pub(crate) fn from_words(
+        variant: P::Variant,
+        solved: Vec<u64>,
+        values: Vec<u64>,
+    ) -> Option<Self> {
+        if (solved.len(), values.len()) != Self::word_counts(&variant) {
+            return None;
+        }
+
+        let len = solved.iter().map(|word| word.count_ones() as usize).sum();
+
+        Some(ArrayStore {
+            variant,
+            solved,
+            values,
+            len,
+
+            _phantom_rv: PhantomData,
+        })
+    }
+
+    fn is_solved(&self, index: usize) -> bool {
+        self.solved[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    /// Word and bit offset of the value at `index`.
+    pub(crate) fn value_slot(index: usize) -> (usize, u32) {
+        let values_per_word = (64 / RV::BITS) as usize;
+
+        (
+            index / values_per_word,
+            (index % values_per_word) as u32 * RV::BITS,
+        )
+    }
+
+    fn value_at(&self, index: usize) -> RV {
+        let (word, offset) = Self::value_slot(index);
+        let mask = u64::MAX >> (64 - RV::BITS);
+
+        RV::unpack(self.values[word] >> offset & mask)
+    }
+}
+
+impl<P, RV> MemoStore<P, RV> for ArrayStore<P, RV>
+where
+    P: PositionHash,
+    RV: PackedValue,
+{
+    fn get(&self, position: &P) -> Option<RV> {
+        let index = position.to_index() as usize;
+
+        match self.is_solved(index) {
+            true => Some(self.value_at(index)),
+            false => None,
+        }
+    }
+
+    fn insert(&mut self, position: P, value: RV) {
+        let index = position.to_index() as usize;
+
+        if !self.is_solved(index) {
+            self.solved[index / 64] |= 1 << (index % 64);
+            self.len += 1;
+        }
+
+        let (word, offset) = Self::value_slot(index);
+        let mask = u64::MAX >> (64 - RV::BITS);
+
+        self.values[word] &= !(mask << offset);
+        self.values[word] |= (value.pack() & mask) << offset;
+    }
+
+    fn contains(&self, position: &P) -> bool {
+        self.is_solved(position.to_index() as usize)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn for_each(&self, mut visit: impl FnMut(&P, &RV)) {
+        for (word_index, &word) in self.solved.iter().enumerate() {
+            let mut word = word;
+
+            while word != 0 {
+                let index = word_index * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
+
+                let position = P::from_index(&self.variant, index as u64);
+                visit(&position, &self.value_at(index));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ArrayStore;
+    use crate::games::mnk::MnkPosition;
+    use crate::games::order_and_chaos::OrderAndChaosGame;
+    use crate::solver::{GameResult, GameResultWithRmt, MemoStore, PositionHash, RmtU32, Solver};
+
+    #[test]
+    fn same_as_hash_map_game_result() {
+        let start = || OrderAndChaosGame::new(3, 3, 3).start_sym_eq();
+
+        let mut hash_map = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut array = Solver::<_, _, _, GameResult, _>::new(ArrayStore::new(start().variant()));
+
+        assert_eq!(hash_map.solve(start()), array.solve(start()));
+        assert_eq!(hash_map.len(), array.len());
+
+        array.for_each_solved(|position, result| {
+            assert_eq!(hash_map.get(position), Some(result));
+        });
+    }
+
+    #[test]
+    fn same_as_hash_map_game_result_with_rmt() {
+        let start = || MnkPosition::start(4, 3, 3);
+
+        let mut hash_map = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut array =
+            Solver::<_, _, _, GameResultWithRmt, _>::new(ArrayStore::new(start().variant()));
+
+        assert_eq!(hash_map.solve(start()), array.solve(start()));
+        assert_eq!(hash_map.len(), array.len());
+
+        for (position, result) in &hash_map {
+            assert_eq!(array.solved_value(position).as_ref(), Some(result));
+        }
+    }
+
+    #[test]
+    fn packed_values_round_trip() {
+        let position = MnkPosition::start(2, 2, 2);
+        let mut store = ArrayStore::<MnkPosition, GameResultWithRmt>::new(position.variant());
+
+        for value in [
+            GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(16_382),
+            },
+            GameResultWithRmt {
+                game_result: GameResult::Draw,
+                rmt: RmtU32::Inf,
+            },
+            GameResultWithRmt {
+                game_result: GameResult::Tie,
+                rmt: RmtU32::Val(0),
+            },
+        ] {
+            store.insert(position.clone(), value);
+
+            assert_eq!(store.get(&position), Some(value));
+        }
+
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.values.len(), 3usize.pow(4).div_ceil(4));
+    }
+}
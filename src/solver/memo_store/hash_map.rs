@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use super::MemoStore;
+use crate::solver::{PositionKey, RecursiveValue};
+
+impl<P, RV> MemoStore<P, RV> for HashMap<P, RV>
+where
+    P: PositionKey,
+    RV: RecursiveValue,
+{
+    fn get(&self, position: &P) -> Option<RV> {
+        HashMap::get(self, position).cloned()
+    }
+
+    fn insert(&mut self, position: P, value: RV) {
+        HashMap::insert(self, position, value);
+    }
+
+    fn contains(&self, position: &P) -> bool {
+        self.contains_key(position)
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn for_each(&self, mut visit: impl FnMut(&P, &RV)) {
+        self.iter()
+            .for_each(|(position, value)| visit(position, value));
+    }
+}
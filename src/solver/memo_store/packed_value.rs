@@ -0,0 +1,75 @@
+use crate::solver::{GameResult, GameResultWithRmt, RmtU32};
+
+/// Values that fit in a fixed number of bits, for `ArrayStore`.
+pub trait PackedValue: Sized {
+    /// Identifies the value type, e.g. in database headers.
+    const NAME: &'static str;
+
+    /// Bits per value. Must divide 64.
+    const BITS: u32;
+
+    fn pack(&self) -> u64;
+
+    fn unpack(bits: u64) -> Self;
+}
+
+impl PackedValue for GameResult {
+    const NAME: &'static str = "game_result";
+
+    const BITS: u32 = 2;
+
+    fn pack(&self) -> u64 {
+        match self {
+            GameResult::Win => 0,
+            GameResult::Lose => 1,
+            GameResult::Tie => 2,
+            GameResult::Draw => 3,
+        }
+    }
+
+    fn unpack(bits: u64) -> Self {
+        match bits {
+            0 => GameResult::Win,
+            1 => GameResult::Lose,
+            2 => GameResult::Tie,
+            _ => GameResult::Draw,
+        }
+    }
+}
+
+/// `RmtU32::Inf` takes the largest 14-bit remoteness.
+const RMT_BITS: u32 = 14;
+const RMT_INF: u64 = (1 << RMT_BITS) - 1;
+
+impl PackedValue for GameResultWithRmt {
+    const NAME: &'static str = "game_result_with_rmt";
+
+    const BITS: u32 = GameResult::BITS + RMT_BITS;
+
+    fn pack(&self) -> u64 {
+        let rmt = match self.rmt {
+            RmtU32::Val(rmt) => {
+                assert!(
+                    (rmt as u64) < RMT_INF,
+                    "Remoteness should fit in {RMT_BITS} bits"
+                );
+                rmt as u64
+            }
+            RmtU32::Inf => RMT_INF,
+        };
+
+        (self.game_result.pack() << RMT_BITS) | rmt
+    }
+
+    fn unpack(bits: u64) -> Self {
+        let rmt = bits & RMT_INF;
+
+        GameResultWithRmt {
+            game_result: GameResult::unpack(bits >> RMT_BITS),
+            rmt: match rmt {
+                RMT_INF => RmtU32::Inf,
+                _ => RmtU32::Val(rmt as u32),
+            },
+        }
+    }
+}
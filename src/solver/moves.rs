@@ -0,0 +1,181 @@
+use super::{
+    MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue, Solver,
+    ToRecursiveValue,
+};
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove + Clone,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+    S: MemoStore<P, RV>,
+{
+    /// Returns (move, child result) for every legal move from `position`.
+    fn move_children_results(&mut self, position: &P) -> Vec<(M, RV)> {
+        if position.primitive_value().is_primitive() {
+            return vec![];
+        }
+
+        position
+            .generate_moves()
+            .into_iter()
+            .map(|mov| {
+                let child_result = self.solve(position.do_move(mov.clone()));
+                (mov, child_result)
+            })
+            .collect()
+    }
+
+    /// Every legal move from `position`, labelled with its value for the
+    /// player making it, e.g. a move to a `Lose` child in 3 is a `Win` in 4.
+    pub fn move_values(&mut self, position: &P) -> Vec<(M, RV)> {
+        self.move_children_results(position)
+            .into_iter()
+            .map(|(mov, child_result)| (mov, RV::recursion_step(&[child_result])))
+            .collect()
+    }
+
+    /// The moves that achieve the value of `position` itself.
+    ///
+    /// With `GameResultWithRmt` these are the winning moves with the smallest
+    /// remoteness, else the tying moves with the smallest remoteness, else the
+    /// drawing moves, else the losing moves with the largest remoteness.
+    pub fn best_moves(&mut self, position: &P) -> Vec<(M, RV)>
+    where
+        RV: PartialEq,
+    {
+        let move_children_results = self.move_children_results(position);
+
+        let children_results = move_children_results
+            .iter()
+            .map(|(_, child_result)| child_result.clone())
+            .collect::<Vec<_>>();
+
+        if children_results.is_empty() {
+            return vec![];
+        }
+
+        let result = RV::recursion_step(&children_results);
+
+        move_children_results
+            .into_iter()
+            .map(|(mov, child_result)| (mov, RV::recursion_step(&[child_result])))
+            .filter(|(_, move_value)| *move_value == result)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::subtraction::SubtractionMove;
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePlayer, TicTacToePosition};
+    use crate::solver::{GameResult, GameResultWithRmt, Position, RmtU32, Solver};
+
+    #[test]
+    fn take_10_to_0() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let move_values = solver.move_values(&take_10_to_0::position(10));
+
+        assert_eq!(
+            move_values,
+            vec![
+                (
+                    SubtractionMove { amount: 1 },
+                    GameResultWithRmt {
+                        game_result: GameResult::Win,
+                        rmt: RmtU32::Val(7),
+                    }
+                ),
+                (
+                    SubtractionMove { amount: 2 },
+                    GameResultWithRmt {
+                        game_result: GameResult::Lose,
+                        rmt: RmtU32::Val(6),
+                    }
+                ),
+            ]
+        );
+
+        let best_moves = solver.best_moves(&take_10_to_0::position(10));
+
+        assert_eq!(best_moves, vec![move_values[0].clone()]);
+
+        // Every move loses, and both lose equally slowly.
+        let best_moves = solver.best_moves(&take_10_to_0::position(9));
+
+        assert_eq!(best_moves, solver.move_values(&take_10_to_0::position(9)));
+        assert_eq!(
+            best_moves[0].1,
+            GameResultWithRmt {
+                game_result: GameResult::Lose,
+                rmt: RmtU32::Val(6),
+            }
+        );
+
+        assert!(solver.best_moves(&take_10_to_0::position(0)).is_empty());
+    }
+
+    #[test]
+    fn tic_tac_toe() {
+        use TicTacToePlayer::{O, X};
+
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        // X to move: winning at once in the top row beats the slower win
+        // through the middle column.
+        let position = TicTacToePosition {
+            board: [
+                [Some(X), Some(X), None],
+                [None, Some(O), None],
+                [Some(O), None, None],
+            ],
+            player: X,
+        };
+
+        let move_values = solver.move_values(&position);
+
+        assert_eq!(5, move_values.len());
+
+        let best_moves = solver.best_moves(&position);
+
+        assert_eq!(1, best_moves.len());
+
+        let (mov, value) = best_moves[0].clone();
+
+        assert_eq!(
+            value,
+            GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(1),
+            }
+        );
+        assert_eq!(
+            position.do_move(mov).board,
+            [
+                [Some(X), Some(X), Some(X)],
+                [None, Some(O), None],
+                [Some(O), None, None]
+            ],
+        );
+    }
+
+    #[test]
+    fn tic_tac_toe_start() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        let position = TicTacToePosition::start();
+
+        let move_values = solver.move_values(&position);
+
+        assert_eq!(9, move_values.len());
+        assert!(move_values
+            .iter()
+            .all(|(_, value)| *value == GameResult::Tie));
+        assert_eq!(9, solver.best_moves(&position).len());
+    }
+}
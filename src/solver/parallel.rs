@@ -0,0 +1,231 @@
+use std::thread;
+
+use super::tiered::SolvedTiers;
+use super::{
+    MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue, Solver, Tier,
+    ToRecursiveValue,
+};
+
+/// Smaller tiers are not worth spawning threads for.
+const MIN_PARALLEL_TIER: usize = 1024;
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey + Tier,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+    S: MemoStore<P, RV>,
+{
+    /// Same as `solve_by_tier`, but each tier's positions are split evenly
+    /// over `threads` threads. A tier's children all lie in later tiers,
+    /// which are already solved and only read, so no position is solved
+    /// twice and lookups take no locks. Finding the tiers is still done by
+    /// one thread.
+    pub fn solve_parallel(&mut self, position: P, threads: usize) -> RV
+    where
+        P: Send + Sync,
+        RV: Send + Sync,
+        S: Sync,
+    {
+        let threads = threads.max(1);
+
+        self.solve_held_tiers(position, |store, positions, start_tier, solved| {
+            match threads == 1 || positions.len() < MIN_PARALLEL_TIER {
+                true => Self::solve_tier(store, positions, start_tier, solved),
+                false => Self::solve_tier_parallel(store, positions, start_tier, solved, threads),
+            }
+        })
+    }
+
+    fn solve_tier_parallel(
+        store: &S,
+        mut positions: Vec<P>,
+        start_tier: u32,
+        solved: &SolvedTiers<P, RV>,
+        threads: usize,
+    ) -> Vec<(P, RV)>
+    where
+        P: Send + Sync,
+        RV: Send + Sync,
+        S: Sync,
+    {
+        let chunk_len = positions.len().div_ceil(threads);
+        let mut chunks = vec![];
+
+        while !positions.is_empty() {
+            chunks.push(positions.split_off(positions.len().saturating_sub(chunk_len)));
+        }
+
+        thread::scope(|scope| {
+            let workers = chunks
+                .into_iter()
+                .map(|chunk| {
+                    scope.spawn(move || Self::solve_tier(store, chunk, start_tier, solved))
+                })
+                .collect::<Vec<_>>();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("worker thread should not panic"))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+    use std::thread;
+    use std::time::Instant;
+
+    use crate::games::connect_four::{ConnectFourPosition, ConnectFourPositionMirrorEq};
+    use crate::games::order_and_chaos::{OrderAndChaosGame, OrderAndChaosPositionD4Eq};
+    use crate::games::take_10_to_0;
+    use crate::games::take_25_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::tic_tac_toe_non_sq::{
+        TicTacToeNonSqPlayer, TicTacToeNonSqPosition, TicTacToeNonSqPositionVEq,
+    };
+    use crate::solver::{ArrayStore, GameResult, GameResultWithRmt, Solver};
+
+    const THREADS: usize = 4;
+
+    #[test]
+    fn same_as_serial_subtraction_games() {
+        let mut serial = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || take_10_to_0::position(100);
+
+        assert_eq!(
+            serial.solve(start()),
+            parallel.solve_parallel(start(), THREADS)
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+
+        let mut serial = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        let start = || take_25_to_0::position(25);
+
+        assert_eq!(
+            serial.solve(start()),
+            parallel.solve_parallel(start(), THREADS)
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+    }
+
+    #[test]
+    fn same_as_serial_tic_tac_toe() {
+        let mut serial = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(
+            serial.solve(TicTacToePosition::start()),
+            parallel.solve_parallel(TicTacToePosition::start(), THREADS),
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+
+        let mut serial = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || TicTacToePositionD4Eq::new(TicTacToePosition::start());
+
+        assert_eq!(
+            serial.solve(start()),
+            parallel.solve_parallel(start(), THREADS)
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+    }
+
+    #[test]
+    fn same_as_serial_tic_tac_toe_non_sq() {
+        let start = || {
+            TicTacToeNonSqPositionVEq::new(TicTacToeNonSqPosition {
+                board: [[None; 4]; 3],
+                player: TicTacToeNonSqPlayer::X,
+            })
+        };
+
+        let mut serial = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            serial.solve(start()),
+            parallel.solve_parallel(start(), THREADS)
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+    }
+
+    #[test]
+    fn same_as_serial_order_and_chaos() {
+        let start = || OrderAndChaosPositionD4Eq::new(OrderAndChaosGame::new(3, 3, 3).start());
+
+        let mut serial = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            serial.solve(start()),
+            parallel.solve_parallel(start(), THREADS)
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+    }
+
+    #[test]
+    fn single_thread() {
+        let mut serial = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(
+            serial.solve(TicTacToePosition::start()),
+            parallel.solve_parallel(TicTacToePosition::start(), 1),
+        );
+        assert_eq!(serial.memoized_map, parallel.memoized_map);
+    }
+
+    #[test]
+    fn array_store() {
+        let mut serial = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResultWithRmt, _>::new(ArrayStore::new(()));
+
+        assert_eq!(
+            serial.solve(TicTacToePosition::start()),
+            parallel.solve_parallel(TicTacToePosition::start(), THREADS),
+        );
+        assert_eq!(serial.len(), parallel.len());
+    }
+
+    /// Needs several cores to mean anything, and `--release` to take less
+    /// than minutes.
+    #[test]
+    #[cfg_attr(not(feature = "slow-tests"), ignore)]
+    fn faster_than_serial() {
+        let threads = thread::available_parallelism().map_or(1, usize::from);
+
+        if threads < 2 {
+            eprintln!("faster_than_serial: skipped, only one core available");
+            return;
+        }
+
+        let start = || ConnectFourPositionMirrorEq::new(ConnectFourPosition::start(5, 4, 4));
+
+        let mut serial = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut parallel = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let serial_start = Instant::now();
+        let serial_result = serial.solve_by_tier(start());
+        let serial_time = serial_start.elapsed();
+
+        let parallel_start = Instant::now();
+        let parallel_result = parallel.solve_parallel(start(), threads);
+        let parallel_time = parallel_start.elapsed();
+
+        eprintln!(
+            "faster_than_serial: {serial_time:?} serial, {parallel_time:?} on {threads} threads"
+        );
+
+        assert_eq!(serial_result, parallel_result);
+        assert!(parallel_time < serial_time);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+
+use super::{
+    GameResult, MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue,
+    Solver, ToGameResult, ToRecursiveValue,
+};
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove + Clone,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue + ToGameResult + PartialEq,
+    S: MemoStore<P, RV>,
+{
+    /// The line of play from `position` where both sides always take the
+    /// first of their `best_moves`, as (move, resulting position) pairs.
+    ///
+    /// With `GameResultWithRmt` the winner takes the fastest win and the
+    /// loser the slowest loss, so the line is exactly as long as the
+    /// remoteness of `position`. The line stops early at a `Draw`, which
+    /// would otherwise go on forever. In loopy games, best moves back to a
+    /// position already on the line are passed over, and the line stops
+    /// when every best move leads back.
+    pub fn perfect_play(&mut self, position: &P) -> Vec<(M, P)>
+    where
+        P: Clone,
+    {
+        let mut line: Vec<(M, P)> = vec![];
+        let mut seen = HashSet::from([position.clone()]);
+
+        loop {
+            let current = line.last().map_or(position, |(_, position)| position);
+
+            let next = self
+                .best_moves(current)
+                .into_iter()
+                .filter(|(_, value)| value.to_game_result() != GameResult::Draw)
+                .map(|(mov, _)| {
+                    let next = current.do_move(mov.clone());
+                    (mov, next)
+                })
+                .find(|(_, next)| !seen.contains(next));
+
+            let Some((mov, next)) = next else {
+                break;
+            };
+
+            seen.insert(next.clone());
+            line.push((mov, next));
+        }
+
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::subtraction::SubtractionMove;
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::TicTacToePosition;
+    use crate::solver::{GameResult, GameResultWithRmt, Position, PrimitiveValue, RmtU32, Solver};
+
+    #[test]
+    fn take_10_to_0() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let line = solver.perfect_play(&take_10_to_0::position(10));
+
+        let remaining_counts = line
+            .iter()
+            .map(|(_, position)| position.remaining_count)
+            .collect::<Vec<_>>();
+
+        assert_eq!(remaining_counts, vec![9, 8, 6, 5, 3, 2, 0]);
+        assert_eq!(line[0].0, SubtractionMove { amount: 1 });
+    }
+
+    #[test]
+    fn tic_tac_toe() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = TicTacToePosition::start();
+
+        let line = solver.perfect_play(&start);
+
+        assert_eq!(9, line.len());
+
+        for (i, (_, position)) in line.iter().enumerate() {
+            assert_eq!(
+                solver.solve(position.clone()),
+                GameResultWithRmt {
+                    game_result: GameResult::Tie,
+                    rmt: RmtU32::Val(8 - i as u32),
+                }
+            );
+        }
+
+        let (_, last) = line.last().expect("line should not be empty");
+
+        assert!(last.primitive_value().is_primitive());
+        assert!(last.generate_moves().is_empty());
+    }
+
+    #[test]
+    fn primitive_position() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert!(solver.perfect_play(&take_10_to_0::position(0)).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::solver::{
+        GameResult, PlayerMove, Position, PositionKey, PrimitiveValue, Solver, ToRecursiveValue,
+    };
+
+    // 0 and 1 can move to each other forever, or both end the game in a
+    // Tie by moving to 2.
+    const EDGES: [&[usize]; 3] = [&[1, 2], &[0, 2], &[]];
+
+    #[derive(Debug)]
+    struct GraphPrimitiveValue {
+        is_tie: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct GraphMove {
+        to: usize,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    struct GraphPosition {
+        node: usize,
+    }
+
+    impl PlayerMove for GraphMove {}
+
+    impl PrimitiveValue for GraphPrimitiveValue {
+        fn is_primitive(&self) -> bool {
+            self.is_tie
+        }
+    }
+
+    impl ToRecursiveValue<GameResult> for GraphPrimitiveValue {
+        fn to_recursive_value(&self) -> Option<GameResult> {
+            self.is_tie.then_some(GameResult::Tie)
+        }
+    }
+
+    impl PositionKey for GraphPosition {}
+
+    impl Position<GraphMove, GraphPrimitiveValue> for GraphPosition {
+        fn do_move(&self, mov: GraphMove) -> GraphPosition {
+            GraphPosition { node: mov.to }
+        }
+
+        fn generate_moves(&self) -> Vec<GraphMove> {
+            EDGES[self.node]
+                .iter()
+                .map(|&to| GraphMove { to })
+                .collect()
+        }
+
+        fn primitive_value(&self) -> GraphPrimitiveValue {
+            GraphPrimitiveValue {
+                is_tie: self.node == 2,
+            }
+        }
+    }
+
+    #[test]
+    fn loopy_graph() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        solver.solve_retrograde(GraphPosition { node: 0 });
+
+        let line = solver.perfect_play(&GraphPosition { node: 0 });
+
+        assert_eq!(
+            line,
+            vec![
+                (GraphMove { to: 1 }, GraphPosition { node: 1 }),
+                (GraphMove { to: 2 }, GraphPosition { node: 2 }),
+            ]
+        );
+    }
+}
@@ -1,6 +1,10 @@
 mod position_grp_elem;
+mod position_hash;
+mod tier;
 
 pub use self::position_grp_elem::PositionKey;
+pub use self::position_hash::PositionHash;
+pub use self::tier::Tier;
 
 use super::{PlayerMove, PrimitiveValue};
 
@@ -0,0 +1,18 @@
+/// A dense, reversible encoding of positions as integers.
+///
+/// Every position reachable in a variant (e.g. a board size) maps to a
+/// distinct index below `index_count`, so a solved game can be stored in a
+/// flat array.
+pub trait PositionHash: Sized {
+    /// Whatever is needed besides the index to rebuild a position, such as
+    /// the board dimensions.
+    type Variant;
+
+    fn variant(&self) -> Self::Variant;
+
+    fn index_count(variant: &Self::Variant) -> u64;
+
+    fn to_index(&self) -> u64;
+
+    fn from_index(variant: &Self::Variant, index: u64) -> Self;
+}
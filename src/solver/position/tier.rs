@@ -0,0 +1,8 @@
+/// Groups positions into tiers, such as the number of pieces on the board.
+///
+/// Every move must lead strictly further away from the tier of the position
+/// the game started in, so tiers can be solved from the last one back to the
+/// first.
+pub trait Tier {
+    fn tier(&self) -> u32;
+}
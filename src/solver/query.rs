@@ -0,0 +1,160 @@
+use std::collections::hash_map::Iter;
+use std::collections::HashMap;
+
+use super::{
+    MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue, Solver,
+    ToRecursiveValue,
+};
+
+/// Read-only access to fully solved positions. None of these ever solve
+/// anything, and partial entries from `solve_value_only` are left out.
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+    S: MemoStore<P, RV>,
+{
+    pub fn solved_value(&self, position: &P) -> Option<RV> {
+        self.memoized_map.get(position)
+    }
+
+    pub fn contains(&self, position: &P) -> bool {
+        self.memoized_map.contains(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.memoized_map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memoized_map.is_empty()
+    }
+
+    /// Calls `visit` on every solved position, in no particular order.
+    pub fn for_each_solved(&self, visit: impl FnMut(&P, &RV)) {
+        self.memoized_map.for_each(visit)
+    }
+
+    pub fn store(&self) -> &S {
+        &self.memoized_map
+    }
+
+    pub fn into_store(self) -> S {
+        self.memoized_map
+    }
+}
+
+/// Borrowing access, only for the default `HashMap` store.
+impl<P, M, PV, RV> Solver<P, M, PV, RV>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+{
+    pub fn get(&self, position: &P) -> Option<&RV> {
+        self.memoized_map.get(position)
+    }
+
+    pub fn iter(&self) -> Iter<'_, P, RV> {
+        self.memoized_map.iter()
+    }
+
+    pub fn into_map(self) -> HashMap<P, RV> {
+        self.memoized_map
+    }
+}
+
+impl<'a, P, M, PV, RV> IntoIterator for &'a Solver<P, M, PV, RV>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+{
+    type Item = (&'a P, &'a RV);
+    type IntoIter = Iter<'a, P, RV>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::mnk::MnkPosition;
+    use crate::games::take_10_to_0::position;
+    use crate::solver::{
+        ArrayStore, GameResult, GameResultWithRmt, MemoStore, PositionHash, Solver,
+    };
+
+    #[test]
+    fn it_works() {
+        let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert!(solver.is_empty());
+        assert_eq!(None, solver.solved_value(&position(10)));
+        assert!(solver.is_empty());
+
+        solver.solve(position(10));
+
+        assert_eq!(11, solver.len());
+        assert!(solver.contains(&position(0)));
+        assert!(!solver.contains(&position(11)));
+        assert_eq!(Some(&GameResult::Lose), solver.get(&position(9)));
+        assert_eq!(Some(GameResult::Win), solver.solved_value(&position(8)));
+        assert_eq!(
+            4,
+            solver
+                .iter()
+                .filter(|(_, &r)| r == GameResult::Lose)
+                .count()
+        );
+        assert_eq!(11, (&solver).into_iter().count());
+
+        let map = solver.into_map();
+
+        assert_eq!(11, map.len());
+        assert_eq!(Some(&GameResult::Win), map.get(&position(7)));
+    }
+
+    #[test]
+    fn partial_entries_are_left_out() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        solver.solve_value_only(position(10));
+
+        assert!(solver.is_partial(&position(10)));
+        assert_eq!(None, solver.solved_value(&position(10)));
+    }
+
+    #[test]
+    fn array_store() {
+        let start = MnkPosition::start(3, 2, 2);
+        let mut solver = Solver::<_, _, _, GameResult, _>::new(ArrayStore::new(start.variant()));
+
+        assert!(solver.is_empty());
+
+        solver.solve(start.clone());
+
+        let mut count = 0;
+        solver.for_each_solved(|_, _| count += 1);
+
+        assert_eq!(solver.len(), count);
+        assert!(solver.contains(&start));
+        assert_eq!(Some(GameResult::Win), solver.solved_value(&start));
+        assert_eq!(count, solver.into_store().len());
+
+        let mut solver =
+            Solver::<_, _, _, GameResultWithRmt, _>::new(ArrayStore::new(start.variant()));
+
+        solver.solve_value_only(start.clone());
+
+        assert!(solver.is_partial(&start));
+        assert_eq!(None, solver.solved_value(&start));
+    }
+}
@@ -1,9 +1,12 @@
 mod game_result;
 mod game_result_with_rmt;
+mod nimber;
 
 pub use game_result::GameResult;
 pub use game_result_with_rmt::GameResultWithRmt;
+pub use game_result_with_rmt::Rmt;
 pub use game_result_with_rmt::RmtU32;
+pub use nimber::Nimber;
 
 pub trait RecursiveValue: Clone {
     fn recursion_step(children: &[Self]) -> Self;
@@ -15,3 +18,27 @@ where
 {
     fn to_recursive_value(&self) -> Option<RV>;
 }
+
+pub trait ToGameResult {
+    /// Whether a value is nothing but its `GameResult`, so it is exact even
+    /// when worked out from only some children.
+    const IS_GAME_RESULT_ONLY: bool = false;
+
+    fn to_game_result(&self) -> GameResult;
+}
+
+/// Swaps `Win` and `Lose`, keeping everything else, e.g. for misère play.
+pub trait FlipWinLose {
+    fn flip_win_lose(&self) -> Self;
+}
+
+/// Remoteness of a value, or `None` for values that do not track it.
+pub trait ToRmt {
+    fn to_rmt(&self) -> Option<RmtU32>;
+}
+
+/// A recursive value that can also describe positions which never resolve,
+/// such as positions caught in a cycle of a loopy game.
+pub trait LoopyRecursiveValue: RecursiveValue + ToGameResult {
+    fn draw() -> Self;
+}
@@ -1,4 +1,4 @@
-use super::RecursiveValue;
+use super::{FlipWinLose, LoopyRecursiveValue, RecursiveValue, RmtU32, ToGameResult, ToRmt};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum GameResult {
@@ -10,15 +10,15 @@ pub enum GameResult {
 
 impl RecursiveValue for GameResult {
     fn recursion_step(children: &[Self]) -> Self {
-        if children.iter().any(|&r| r == GameResult::Lose) {
+        if children.contains(&GameResult::Lose) {
             return GameResult::Win;
         }
 
-        if children.iter().any(|&r| r == GameResult::Tie) {
+        if children.contains(&GameResult::Tie) {
             return GameResult::Tie;
         }
 
-        if children.iter().any(|&r| r == GameResult::Draw) {
+        if children.contains(&GameResult::Draw) {
             return GameResult::Draw;
         }
 
@@ -26,22 +26,50 @@ impl RecursiveValue for GameResult {
     }
 }
 
+impl ToGameResult for GameResult {
+    const IS_GAME_RESULT_ONLY: bool = true;
+
+    fn to_game_result(&self) -> GameResult {
+        *self
+    }
+}
+
+impl FlipWinLose for GameResult {
+    fn flip_win_lose(&self) -> Self {
+        match self {
+            GameResult::Win => GameResult::Lose,
+            GameResult::Lose => GameResult::Win,
+            GameResult::Tie => GameResult::Tie,
+            GameResult::Draw => GameResult::Draw,
+        }
+    }
+}
+
+impl ToRmt for GameResult {
+    fn to_rmt(&self) -> Option<RmtU32> {
+        None
+    }
+}
+
+impl LoopyRecursiveValue for GameResult {
+    fn draw() -> Self {
+        GameResult::Draw
+    }
+}
+
 #[cfg(test)]
 mod tests_with_games {
     use std::collections::HashMap;
 
     use super::GameResult;
-    use crate::games::tic_tac_toe::{TicTacToePlayer, TicTacToePosition};
+    use crate::games::tic_tac_toe::TicTacToePosition;
     use crate::solver::{Position, PrimitiveValue, Solver};
 
     #[test]
     fn it_works() {
         let mut solver = Solver::new(HashMap::new());
 
-        let result: GameResult = solver.solve(TicTacToePosition {
-            board: [[None, None, None], [None, None, None], [None, None, None]],
-            player: TicTacToePlayer::X,
-        });
+        let result: GameResult = solver.solve(TicTacToePosition::start());
 
         assert_eq!(result, GameResult::Tie);
     }
@@ -50,33 +78,20 @@ mod tests_with_games {
     fn tic_tac_toe_counts() {
         let mut solver = Solver::<_, _, _, GameResult>::new(HashMap::new());
 
-        solver.solve(TicTacToePosition {
-            board: [[None, None, None], [None, None, None], [None, None, None]],
-            player: TicTacToePlayer::X,
-        });
+        solver.solve(TicTacToePosition::start());
 
-        let wins = solver
-            .memoized_map
-            .iter()
-            .filter(|(_, &r)| r == GameResult::Win)
-            .count();
+        let wins = solver.iter().filter(|(_, &r)| r == GameResult::Win).count();
 
         let loses = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r == GameResult::Lose)
             .count();
 
-        let ties = solver
-            .memoized_map
-            .iter()
-            .filter(|(_, &r)| r == GameResult::Tie)
-            .count();
+        let ties = solver.iter().filter(|(_, &r)| r == GameResult::Tie).count();
 
-        let total = solver.memoized_map.iter().count();
+        let total = solver.len();
 
         let prim_wins = solver
-            .memoized_map
             .iter()
             .filter(|(position, &r)| {
                 r == GameResult::Win && position.primitive_value().is_primitive()
@@ -84,7 +99,6 @@ mod tests_with_games {
             .count();
 
         let prim_loses = solver
-            .memoized_map
             .iter()
             .filter(|(position, &r)| {
                 r == GameResult::Lose && position.primitive_value().is_primitive()
@@ -92,7 +106,6 @@ mod tests_with_games {
             .count();
 
         let prim_ties = solver
-            .memoized_map
             .iter()
             .filter(|(position, &r)| {
                 r == GameResult::Tie && position.primitive_value().is_primitive()
@@ -100,7 +113,6 @@ mod tests_with_games {
             .count();
 
         let prim_total = solver
-            .memoized_map
             .iter()
             .filter(|(position, _)| position.primitive_value().is_primitive())
             .count();
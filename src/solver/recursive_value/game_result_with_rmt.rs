@@ -1,6 +1,6 @@
-use super::{GameResult, RecursiveValue};
+use super::{FlipWinLose, GameResult, LoopyRecursiveValue, RecursiveValue, ToGameResult, ToRmt};
 
-trait Rmt {
+pub trait Rmt {
     fn inf() -> Self;
     fn is_inf(&self) -> bool;
     fn increment(&self) -> Self;
@@ -54,7 +54,6 @@ impl RecursiveValue for GameResultWithRmt {
                     *game_result == keep_game_result
                 })
                 .map(|GameResultWithRmt { rmt, .. }| *rmt)
-                .into_iter()
         };
 
         match game_result {
@@ -87,6 +86,36 @@ impl RecursiveValue for GameResultWithRmt {
     }
 }
 
+impl ToGameResult for GameResultWithRmt {
+    fn to_game_result(&self) -> GameResult {
+        self.game_result
+    }
+}
+
+impl FlipWinLose for GameResultWithRmt {
+    fn flip_win_lose(&self) -> Self {
+        GameResultWithRmt {
+            game_result: self.game_result.flip_win_lose(),
+            rmt: self.rmt,
+        }
+    }
+}
+
+impl ToRmt for GameResultWithRmt {
+    fn to_rmt(&self) -> Option<RmtU32> {
+        Some(self.rmt)
+    }
+}
+
+impl LoopyRecursiveValue for GameResultWithRmt {
+    fn draw() -> Self {
+        GameResultWithRmt {
+            game_result: GameResult::Draw,
+            rmt: RmtU32::inf(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::RmtU32;
@@ -102,12 +131,12 @@ mod tests {
         let inf = RmtU32::Inf;
 
         assert_eq!(min, zero);
-        assert_eq!(zero < one, true);
-        assert_eq!(one < two, true);
-        assert_eq!(two < max_minus_one, true);
-        assert_eq!(max < max_minus_one, false);
-        assert_eq!(inf < max, false);
-        assert_eq!(zero < inf, true);
+        assert!(zero < one);
+        assert!(one < two);
+        assert!(two < max_minus_one);
+        assert!(max >= max_minus_one);
+        assert!(inf >= max);
+        assert!(zero < inf);
     }
 }
 
@@ -116,8 +145,8 @@ mod tests_with_games {
     use std::collections::HashMap;
 
     use super::{GameResultWithRmt, RmtU32};
-    use crate::games::take_10_to_0::TenToZeroPosition;
-    use crate::games::tic_tac_toe::{TicTacToePlayer, TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
     use crate::games::tic_tac_toe_non_sq::{
         TicTacToeNonSqPlayer, TicTacToeNonSqPosition, TicTacToeNonSqPositionVEq,
     };
@@ -127,9 +156,7 @@ mod tests_with_games {
     fn it_works() {
         let mut solver = Solver::new(HashMap::<_, GameResultWithRmt>::new());
 
-        let result = solver.solve(TenToZeroPosition {
-            remaining_count: 10,
-        });
+        let result = solver.solve(take_10_to_0::position(10));
 
         assert_eq!(
             result,
@@ -140,7 +167,7 @@ mod tests_with_games {
         );
 
         for i in (0..=10).rev() {
-            let result = solver.solve(TenToZeroPosition { remaining_count: i });
+            let result = solver.solve(take_10_to_0::position(i));
 
             println!("{i}: {:?}", result);
         }
@@ -150,10 +177,9 @@ mod tests_with_games {
     fn tic_tac_toe() {
         let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
 
-        let result = solver.solve(TicTacToePosition {
-            board: [[None, None, None], [None, None, None], [None, None, None]],
-            player: TicTacToePlayer::X,
-        });
+        let start = TicTacToePosition::start();
+
+        let result = solver.solve(start.clone());
 
         assert_eq!(
             result,
@@ -164,34 +190,34 @@ mod tests_with_games {
         );
 
         println!("{:#?}", result);
+
+        let line = solver.perfect_play(&start);
+
+        assert_eq!(9, line.len());
+
+        for (mov, position) in line {
+            println!("{:?}: {:?}", mov, position.board);
+        }
     }
 
     #[test]
     fn tic_tac_toe_counts() {
         let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
 
-        solver.solve(TicTacToePositionD4Eq {
-            position: TicTacToePosition {
-                board: [[None, None, None], [None, None, None], [None, None, None]],
-                player: TicTacToePlayer::X,
-            },
-        });
+        solver.solve(TicTacToePositionD4Eq::new(TicTacToePosition::start()));
 
         for rmt in 0..=10 {
             let wins = solver
-                .memoized_map
                 .iter()
                 .filter(|(_, &r)| r.game_result == GameResult::Win && (r.rmt == RmtU32::Val(rmt)))
                 .count();
 
             let loses = solver
-                .memoized_map
                 .iter()
                 .filter(|(_, &r)| r.game_result == GameResult::Lose && (r.rmt == RmtU32::Val(rmt)))
                 .count();
 
             let ties = solver
-                .memoized_map
                 .iter()
                 .filter(|(_, &r)| r.game_result == GameResult::Tie && (r.rmt == RmtU32::Val(rmt)))
                 .count();
@@ -203,24 +229,21 @@ mod tests_with_games {
         }
 
         let wins = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r.game_result == GameResult::Win)
             .count();
 
         let loses = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r.game_result == GameResult::Lose)
             .count();
 
         let ties = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r.game_result == GameResult::Tie)
             .count();
 
-        let total = solver.memoized_map.iter().count();
+        let total = solver.len();
 
         assert_eq!(390, wins);
         assert_eq!(224, loses);
@@ -237,32 +260,27 @@ mod tests_with_games {
     fn tic_tac_toe_non_sq_counts() {
         let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
 
-        solver.solve(TicTacToeNonSqPositionVEq {
-            position: TicTacToeNonSqPosition {
-                board: [
-                    [None, None, None, None],
-                    [None, None, None, None],
-                    [None, None, None, None],
-                ],
-                player: TicTacToeNonSqPlayer::X,
-            },
-        });
+        solver.solve(TicTacToeNonSqPositionVEq::new(TicTacToeNonSqPosition {
+            board: [
+                [None, None, None, None],
+                [None, None, None, None],
+                [None, None, None, None],
+            ],
+            player: TicTacToeNonSqPlayer::X,
+        }));
 
         for rmt in 0..=10 {
             let wins = solver
-                .memoized_map
                 .iter()
                 .filter(|(_, &r)| r.game_result == GameResult::Win && (r.rmt == RmtU32::Val(rmt)))
                 .count();
 
             let loses = solver
-                .memoized_map
                 .iter()
                 .filter(|(_, &r)| r.game_result == GameResult::Lose && (r.rmt == RmtU32::Val(rmt)))
                 .count();
 
             let ties = solver
-                .memoized_map
                 .iter()
                 .filter(|(_, &r)| r.game_result == GameResult::Tie && (r.rmt == RmtU32::Val(rmt)))
                 .count();
@@ -274,24 +292,21 @@ mod tests_with_games {
         }
 
         let wins = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r.game_result == GameResult::Win)
             .count();
 
         let loses = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r.game_result == GameResult::Lose)
             .count();
 
         let ties = solver
-            .memoized_map
             .iter()
             .filter(|(_, &r)| r.game_result == GameResult::Tie)
             .count();
 
-        let total = solver.memoized_map.iter().count();
+        let total = solver.len();
 
         // assert_eq!(390, wins);
         // assert_eq!(224, loses);
@@ -0,0 +1,74 @@
+use std::ops::BitXor;
+
+use super::{GameResult, RecursiveValue, ToGameResult};
+
+/// Sprague-Grundy value of a position in an impartial game under normal
+/// play. A position is a `Lose` exactly when its nimber is 0.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Nimber(pub u32);
+
+impl RecursiveValue for Nimber {
+    fn recursion_step(children: &[Self]) -> Self {
+        let mut seen = vec![false; children.len() + 1];
+
+        for &Nimber(n) in children {
+            if let Some(seen) = seen.get_mut(n as usize) {
+                *seen = true;
+            }
+        }
+
+        let mex = seen
+            .iter()
+            .position(|&seen| !seen)
+            .expect("some value up to children.len() should be missing");
+
+        Nimber(mex as u32)
+    }
+}
+
+impl ToGameResult for Nimber {
+    fn to_game_result(&self) -> GameResult {
+        match self {
+            Nimber(0) => GameResult::Lose,
+            _ => GameResult::Win,
+        }
+    }
+}
+
+/// The nimber of a disjunctive sum is the XOR of its components' nimbers.
+impl BitXor for Nimber {
+    type Output = Nimber;
+
+    fn bitxor(self, rhs: Nimber) -> Nimber {
+        Nimber(self.0 ^ rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nimber;
+    use crate::solver::{GameResult, RecursiveValue, ToGameResult};
+
+    #[test]
+    fn mex() {
+        assert_eq!(Nimber::recursion_step(&[]), Nimber(0));
+        assert_eq!(Nimber::recursion_step(&[Nimber(0)]), Nimber(1));
+        assert_eq!(Nimber::recursion_step(&[Nimber(1), Nimber(2)]), Nimber(0));
+        assert_eq!(
+            Nimber::recursion_step(&[Nimber(3), Nimber(0), Nimber(1), Nimber(0)]),
+            Nimber(2)
+        );
+        assert_eq!(
+            Nimber::recursion_step(&[Nimber(0), Nimber(1), Nimber(2)]),
+            Nimber(3)
+        );
+    }
+
+    #[test]
+    fn xor() {
+        assert_eq!(Nimber(5) ^ Nimber(3), Nimber(6));
+        assert_eq!(Nimber(7) ^ Nimber(7), Nimber(0));
+        assert_eq!((Nimber(7) ^ Nimber(7)).to_game_result(), GameResult::Lose);
+        assert_eq!(Nimber(1).to_game_result(), GameResult::Win);
+    }
+}
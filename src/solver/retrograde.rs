@@ -0,0 +1,428 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::{
+    GameResult, LoopyRecursiveValue, MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue,
+    RecursiveValue, Solver, ToRecursiveValue, ToRmt,
+};
+
+struct Node<RV> {
+    children: Vec<usize>,
+    parents: Vec<usize>,
+    unresolved_children: usize,
+    value: Option<RV>,
+    /// Whether `value` came from the memo store, so need not be stored again.
+    memoized: bool,
+}
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+    S: MemoStore<P, RV>,
+{
+    /// Solves games whose position graph may contain cycles.
+    ///
+    /// The reachable graph is built first, then values are propagated back
+    /// from the primitive positions: Win and Lose first, then Tie. Positions
+    /// that are still unresolved after that can never be forced to an end and
+    /// come out as `RV::draw()`. Positions already memoized are leaves of the
+    /// graph, with their memoized values.
+    pub fn solve_retrograde(&mut self, position: P) -> RV
+    where
+        P: Clone,
+        RV: LoopyRecursiveValue + ToRmt,
+    {
+        if let Some(result) = self.memoized_map.get(&position) {
+            return result;
+        }
+
+        let mut indices = HashMap::from([(position.clone(), 0)]);
+        let mut positions = vec![position];
+        let mut nodes: Vec<Node<RV>> = vec![];
+
+        // Discover the reachable graph in BFS order.
+        while nodes.len() < positions.len() {
+            let index = nodes.len();
+            let position = &positions[index];
+
+            let memoized = self.memoized_map.get(position);
+            let is_memoized = memoized.is_some();
+            let known = memoized.or_else(|| position.primitive_value().to_recursive_value());
+
+            let children = match known {
+                Some(_) => vec![],
+                None => self.children(position),
+            };
+
+            let children = children
+                .into_iter()
+                .map(|child| match indices.get(&child) {
+                    Some(&child_index) => child_index,
+                    None => {
+                        let child_index = positions.len();
+                        indices.insert(child.clone(), child_index);
+                        positions.push(child);
+                        child_index
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            nodes.push(Node {
+                unresolved_children: children.len(),
+                children,
+                parents: vec![],
+                value: known,
+                memoized: is_memoized,
+            });
+        }
+
+        drop(positions);
+
+        for index in 0..nodes.len() {
+            for child_index in nodes[index].children.clone() {
+                nodes[child_index].parents.push(index);
+            }
+        }
+
+        // Both queues pop the smallest remoteness first, so the first child
+        // that resolves a parent is also the closest one, even when memoized
+        // leaves start out far from the end.
+        let mut win_lose_queue = BTreeSet::new();
+        let mut tie_queue = BTreeSet::new();
+
+        for (index, node) in nodes.iter_mut().enumerate() {
+            if node.value.is_none() && node.children.is_empty() {
+                node.value = Some(RV::recursion_step(&[]));
+            }
+
+            let rmt = node.value.as_ref().and_then(ToRmt::to_rmt);
+
+            match node.value.as_ref().map(|value| value.to_game_result()) {
+                Some(GameResult::Win | GameResult::Lose) => {
+                    win_lose_queue.insert((rmt, index));
+                }
+                Some(GameResult::Tie) => {
+                    tie_queue.insert((rmt, index));
+                }
+                Some(GameResult::Draw) | None => (),
+            }
+        }
+
+        while let Some((_, index)) = win_lose_queue.pop_first() {
+            let value = nodes[index].value.clone().expect("queued node is resolved");
+
+            for parent_index in nodes[index].parents.clone() {
+                if nodes[parent_index].value.is_some() {
+                    continue;
+                }
+
+                nodes[parent_index].unresolved_children -= 1;
+
+                let parent_value = match value.to_game_result() {
+                    GameResult::Lose => RV::recursion_step(std::slice::from_ref(&value)),
+                    _ if nodes[parent_index].unresolved_children == 0 => {
+                        let children_results = nodes[parent_index]
+                            .children
+                            .iter()
+                            .map(|&child_index| {
+                                nodes[child_index]
+                                    .value
+                                    .clone()
+                                    .expect("all children are resolved")
+                            })
+                            .collect::<Vec<_>>();
+
+                        RV::recursion_step(&children_results)
+                    }
+                    _ => continue,
+                };
+
+                win_lose_queue.insert((parent_value.to_rmt(), parent_index));
+                nodes[parent_index].value = Some(parent_value);
+            }
+        }
+
+        // Whatever is left has no Lose child, so a Tie child is the best it
+        // can do.
+        while let Some((_, index)) = tie_queue.pop_first() {
+            let value = nodes[index].value.clone().expect("queued node is resolved");
+
+            for parent_index in nodes[index].parents.clone() {
+                if nodes[parent_index].value.is_some() {
+                    continue;
+                }
+
+                let parent_value = RV::recursion_step(std::slice::from_ref(&value));
+
+                tie_queue.insert((parent_value.to_rmt(), parent_index));
+                nodes[parent_index].value = Some(parent_value);
+            }
+        }
+
+        let mut values = nodes
+            .into_iter()
+            .map(|node| match node.memoized {
+                true => None,
+                false => Some(node.value.unwrap_or_else(RV::draw)),
+            })
+            .collect::<Vec<_>>();
+
+        let result = values[0].clone().expect("position is not memoized");
+
+        for (position, index) in indices {
+            if let Some(value) = values[index].take() {
+                self.memoize(position, value);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::solver::{
+        GameResult, GameResultWithRmt, MemoStore, PlayerMove, Position, PositionKey,
+        PrimitiveValue, RmtU32, Solver, ToRecursiveValue,
+    };
+
+    // A small loopy game played on a fixed directed graph.
+    //
+    //   0 -> 1, 2      1 -> 0, 3      2 -> 2         3: primitive Lose
+    //   4 -> 5, 7      5 -> 4, 6      6: primitive Win
+    //   7 -> 8, 9      8: primitive Tie               9 -> 7
+    const EDGES: [&[usize]; 10] = [
+        &[1, 2],
+        &[0, 3],
+        &[2],
+        &[],
+        &[5, 7],
+        &[4, 6],
+        &[],
+        &[8, 9],
+        &[],
+        &[7],
+    ];
+
+    #[derive(Debug)]
+    enum GraphPrimitiveValue {
+        Win,
+        Lose,
+        Tie,
+        NotPrimitive,
+    }
+
+    #[derive(Debug)]
+    struct GraphMove {
+        to: usize,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    struct GraphPosition {
+        node: usize,
+    }
+
+    impl PlayerMove for GraphMove {}
+
+    impl PrimitiveValue for GraphPrimitiveValue {
+        fn is_primitive(&self) -> bool {
+            !matches!(self, GraphPrimitiveValue::NotPrimitive)
+        }
+    }
+
+    impl ToRecursiveValue<GameResultWithRmt> for GraphPrimitiveValue {
+        fn to_recursive_value(&self) -> Option<GameResultWithRmt> {
+            let game_result = match self {
+                GraphPrimitiveValue::Win => GameResult::Win,
+                GraphPrimitiveValue::Lose => GameResult::Lose,
+                GraphPrimitiveValue::Tie => GameResult::Tie,
+                GraphPrimitiveValue::NotPrimitive => return None,
+            };
+
+            Some(GameResultWithRmt {
+                game_result,
+                rmt: RmtU32::Val(0),
+            })
+        }
+    }
+
+    impl PositionKey for GraphPosition {}
+
+    impl Position<GraphMove, GraphPrimitiveValue> for GraphPosition {
+        fn do_move(&self, mov: GraphMove) -> GraphPosition {
+            GraphPosition { node: mov.to }
+        }
+
+        fn generate_moves(&self) -> Vec<GraphMove> {
+            EDGES[self.node]
+                .iter()
+                .map(|&to| GraphMove { to })
+                .collect()
+        }
+
+        fn primitive_value(&self) -> GraphPrimitiveValue {
+            match self.node {
+                3 => GraphPrimitiveValue::Lose,
+                6 => GraphPrimitiveValue::Win,
+                8 => GraphPrimitiveValue::Tie,
+                _ => GraphPrimitiveValue::NotPrimitive,
+            }
+        }
+    }
+
+    fn value(game_result: GameResult, rmt: RmtU32) -> GameResultWithRmt {
+        GameResultWithRmt { game_result, rmt }
+    }
+
+    #[test]
+    fn loopy_graph() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = solver.solve_retrograde(GraphPosition { node: 0 });
+        assert_eq!(result, value(GameResult::Draw, RmtU32::Inf));
+
+        let expected = [
+            (1, value(GameResult::Win, RmtU32::Val(1))),
+            (2, value(GameResult::Draw, RmtU32::Inf)),
+            (3, value(GameResult::Lose, RmtU32::Val(0))),
+        ];
+
+        for (node, expected) in expected {
+            assert_eq!(solver.memoized_map[&GraphPosition { node }], expected);
+        }
+        assert_eq!(4, solver.memoized_map.len());
+
+        let result = solver.solve_retrograde(GraphPosition { node: 4 });
+        assert_eq!(result, value(GameResult::Tie, RmtU32::Val(2)));
+
+        let expected = [
+            (5, value(GameResult::Tie, RmtU32::Val(3))),
+            (6, value(GameResult::Win, RmtU32::Val(0))),
+            (7, value(GameResult::Tie, RmtU32::Val(1))),
+            (8, value(GameResult::Tie, RmtU32::Val(0))),
+            (9, value(GameResult::Tie, RmtU32::Val(2))),
+        ];
+
+        for (node, expected) in expected {
+            assert_eq!(solver.memoized_map[&GraphPosition { node }], expected);
+        }
+        assert_eq!(10, solver.memoized_map.len());
+    }
+
+    #[test]
+    fn memoized_positions_are_leaves() {
+        // Node 2 only loops back to itself, so on its own it is a Draw.
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::from([(
+            GraphPosition { node: 2 },
+            value(GameResult::Lose, RmtU32::Val(5)),
+        )]));
+
+        let result = solver.solve_retrograde(GraphPosition { node: 0 });
+        assert_eq!(result, value(GameResult::Win, RmtU32::Val(6)));
+
+        assert_eq!(
+            solver.memoized_map[&GraphPosition { node: 2 }],
+            value(GameResult::Lose, RmtU32::Val(5))
+        );
+        assert_eq!(
+            solver.memoized_map[&GraphPosition { node: 1 }],
+            value(GameResult::Win, RmtU32::Val(1))
+        );
+    }
+
+    /// Fails the test if any position is stored twice.
+    struct StoreOnce(HashMap<GraphPosition, GameResultWithRmt>);
+
+    impl MemoStore<GraphPosition, GameResultWithRmt> for StoreOnce {
+        fn get(&self, position: &GraphPosition) -> Option<GameResultWithRmt> {
+            self.0.get(position).cloned()
+        }
+
+        fn insert(&mut self, position: GraphPosition, value: GameResultWithRmt) {
+            let node = position.node;
+
+            assert!(
+                self.0.insert(position, value).is_none(),
+                "node {node} stored twice"
+            );
+        }
+
+        fn contains(&self, position: &GraphPosition) -> bool {
+            self.0.contains_key(position)
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn for_each(&self, mut visit: impl FnMut(&GraphPosition, &GameResultWithRmt)) {
+            self.0
+                .iter()
+                .for_each(|(position, value)| visit(position, value));
+        }
+    }
+
+    #[test]
+    fn memoized_positions_are_not_stored_again() {
+        let mut solver =
+            Solver::<_, _, _, GameResultWithRmt, _>::new(StoreOnce(HashMap::from([(
+                GraphPosition { node: 2 },
+                value(GameResult::Lose, RmtU32::Val(5)),
+            )])));
+
+        solver.solve_retrograde(GraphPosition { node: 0 });
+        solver.solve_retrograde(GraphPosition { node: 7 });
+
+        let result = solver.solve_retrograde(GraphPosition { node: 4 });
+        assert_eq!(result, value(GameResult::Tie, RmtU32::Val(2)));
+        assert_eq!(10, solver.len());
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::solver::{GameResult, GameResultWithRmt, Solver};
+
+    #[test]
+    fn same_as_recursive_take_10_to_0() {
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut retrograde = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            recursive.solve(take_10_to_0::position(100)),
+            retrograde.solve_retrograde(take_10_to_0::position(100)),
+        );
+        assert_eq!(recursive.memoized_map, retrograde.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_tic_tac_toe() {
+        let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut retrograde = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(
+            recursive.solve(TicTacToePosition::start()),
+            retrograde.solve_retrograde(TicTacToePosition::start()),
+        );
+        assert_eq!(recursive.memoized_map, retrograde.memoized_map);
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut retrograde = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || TicTacToePositionD4Eq::new(TicTacToePosition::start());
+
+        assert_eq!(
+            recursive.solve(start()),
+            retrograde.solve_retrograde(start())
+        );
+        assert_eq!(recursive.memoized_map, retrograde.memoized_map);
+    }
+}
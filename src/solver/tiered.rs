@@ -0,0 +1,440 @@
+mod tier_file;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
+use std::io;
+use std::iter;
+
+use self::tier_file::TierFile;
+use super::{
+    MemoStore, PlayerMove, Position, PositionHash, PositionKey, PrimitiveValue, RecursiveValue,
+    Solver, Tier, ToRecursiveValue,
+};
+
+/// Solved tiers by distance from the start position's tier.
+pub(super) type SolvedTiers<P, RV> = BTreeMap<u32, HashMap<P, RV>>;
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey + Tier,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue,
+    S: MemoStore<P, RV>,
+{
+    /// Calls `visit` on every tier reachable from `position`, nearest first,
+    /// with its distance from `position`'s tier, until `visit` returns
+    /// `false`. Only tiers a single move can still reach are held at once.
+    /// Returns the largest distance a single move covered.
+    fn walk_tiers(&self, position: &P, mut visit: impl FnMut(u32, Vec<P>) -> bool) -> u32 {
+        let start_tier = position.tier();
+
+        let mut unexpanded = BTreeMap::new();
+        let mut max_step = 0;
+
+        let mut expand = |position: &P, distance: u32, unexpanded: &mut BTreeMap<_, HashSet<_>>| {
+            if self.memoized_map.contains(position) || position.primitive_value().is_primitive() {
+                return;
+            }
+
+            for child in self.children(position) {
+                let child_distance = start_tier.abs_diff(child.tier());
+
+                assert!(
+                    child_distance > distance,
+                    "Moves should lead to a later tier"
+                );
+
+                max_step = max_step.max(child_distance - distance);
+
+                unexpanded.entry(child_distance).or_default().insert(child);
+            }
+        };
+
+        expand(position, 0, &mut unexpanded);
+
+        while let Some((distance, positions)) = unexpanded.pop_first() {
+            positions
+                .iter()
+                .for_each(|position| expand(position, distance, &mut unexpanded));
+
+            if !visit(distance, positions.into_iter().collect()) {
+                break;
+            }
+        }
+
+        max_step
+    }
+
+    /// Solves `position` from `store` and the later tiers in `solved`.
+    fn tier_result(store: &S, position: &P, start_tier: u32, solved: &SolvedTiers<P, RV>) -> RV {
+        if let Some(result) = store.get(position) {
+            return result;
+        }
+
+        if let Some(result) = position.primitive_value().to_recursive_value() {
+            return result;
+        }
+
+        let children_results = position
+            .generate_moves()
+            .into_iter()
+            .map(|mov| {
+                let child = position.do_move(mov);
+                let child_distance = start_tier.abs_diff(child.tier());
+
+                solved
+                    .get(&child_distance)
+                    .and_then(|tier_map| tier_map.get(&child))
+                    .cloned()
+                    .or_else(|| store.get(&child))
+                    .expect("later tiers should be solved")
+            })
+            .collect::<Vec<_>>();
+
+        RV::recursion_step(&children_results)
+    }
+
+    /// Solves every position of one tier, all of whose children are in
+    /// `store` or `solved`.
+    pub(super) fn solve_tier(
+        store: &S,
+        positions: Vec<P>,
+        start_tier: u32,
+        solved: &SolvedTiers<P, RV>,
+    ) -> Vec<(P, RV)> {
+        positions
+            .into_iter()
+            .map(|position| {
+                let result = Self::tier_result(store, &position, start_tier, solved);
+
+                (position, result)
+            })
+            .collect()
+    }
+
+    /// Solves `tiers`, farthest first, back to `position`'s, handing each
+    /// tier to `solve_tier`. `max_step` is the largest distance a single
+    /// move covers.
+    fn solve_tiers<E>(
+        &mut self,
+        position: P,
+        tiers: impl Iterator<Item = Result<(u32, Vec<P>), E>>,
+        max_step: u32,
+        keep_solved_tiers: bool,
+        solve_tier: impl Fn(&S, Vec<P>, u32, &SolvedTiers<P, RV>) -> Vec<(P, RV)>,
+    ) -> Result<RV, E> {
+        if let Some(result) = self.memoized_map.get(&position) {
+            return Ok(result);
+        }
+
+        let start_tier = position.tier();
+        let mut solved = SolvedTiers::new();
+
+        for tier in tiers {
+            let (distance, positions) = tier?;
+
+            let tier_map = solve_tier(&self.memoized_map, positions, start_tier, &solved);
+
+            solved.insert(distance, tier_map.into_iter().collect());
+
+            // Tiers beyond this point cannot be reached from any tier that is
+            // still left to solve.
+            while let Some(entry) = solved.last_entry() {
+                if *entry.key() < distance + max_step {
+                    break;
+                }
+
+                let tier_map = entry.remove();
+
+                if keep_solved_tiers {
+                    for (position, result) in tier_map {
+                        self.memoize(position, result);
+                    }
+                }
+            }
+        }
+
+        let result = Self::tier_result(&self.memoized_map, &position, start_tier, &solved);
+
+        if keep_solved_tiers {
+            for (_, tier_map) in solved {
+                for (position, result) in tier_map {
+                    self.memoize(position, result);
+                }
+            }
+        }
+
+        self.memoize(position, result.clone());
+
+        Ok(result)
+    }
+
+    /// Walks every tier into memory, then solves and memoizes them all,
+    /// handing each tier to `solve_tier`.
+    pub(super) fn solve_held_tiers(
+        &mut self,
+        position: P,
+        solve_tier: impl Fn(&S, Vec<P>, u32, &SolvedTiers<P, RV>) -> Vec<(P, RV)>,
+    ) -> RV {
+        let mut tiers = vec![];
+        let max_step = self.walk_tiers(&position, |distance, positions| {
+            tiers.push((distance, positions));
+
+            true
+        });
+
+        let tiers = tiers.into_iter().rev().map(Ok::<_, Infallible>);
+        let Ok(result) = self.solve_tiers(position, tiers, max_step, true, solve_tier);
+
+        result
+    }
+
+    /// Solves every position reachable from `position` one tier at a time,
+    /// starting from the last tier, without any recursion.
+    pub fn solve_by_tier(&mut self, position: P) -> RV {
+        self.solve_held_tiers(position, Self::solve_tier)
+    }
+
+    /// Same as `solve_by_tier`, but only a few tiers are held in memory at a
+    /// time, so only `position` itself is memoized. The walk spills each
+    /// tier to a temporary file as `PositionHash` indices, which are read
+    /// back one tier at a time, and each solved tier is dropped as soon as no
+    /// tier left to solve can reach it.
+    pub fn solve_by_tier_discarding(&mut self, position: P) -> io::Result<RV>
+    where
+        P: PositionHash,
+    {
+        let variant = position.variant();
+        let mut tier_file = TierFile::create()?;
+
+        let mut spilled = Ok(());
+        let max_step = self.walk_tiers(&position, |distance, positions| {
+            spilled = tier_file.push(distance, &positions);
+
+            spilled.is_ok()
+        });
+        spilled?;
+
+        let tiers = iter::from_fn(|| tier_file.pop(&variant).transpose());
+
+        self.solve_tiers(position, tiers, max_step, false, Self::solve_tier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::solver::{
+        GameResult, PlayerMove, Position, PositionHash, PositionKey, PrimitiveValue, Solver, Tier,
+        ToRecursiveValue,
+    };
+
+    const LAST: u32 = 25;
+
+    // Players take turns adding 1 or 2 to a count, without going past LAST.
+    // Whoever reaches LAST wins, and a move of 2 skips a tier.
+    #[derive(Debug)]
+    struct CountPrimitiveValue {
+        is_lose: bool,
+    }
+
+    #[derive(Debug)]
+    struct CountMove {
+        amount: u32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    struct CountPosition {
+        count: u32,
+    }
+
+    impl PlayerMove for CountMove {}
+
+    impl PrimitiveValue for CountPrimitiveValue {
+        fn is_primitive(&self) -> bool {
+            self.is_lose
+        }
+    }
+
+    impl ToRecursiveValue<GameResult> for CountPrimitiveValue {
+        fn to_recursive_value(&self) -> Option<GameResult> {
+            self.is_lose.then_some(GameResult::Lose)
+        }
+    }
+
+    impl PositionKey for CountPosition {}
+
+    impl Tier for CountPosition {
+        fn tier(&self) -> u32 {
+            self.count
+        }
+    }
+
+    impl PositionHash for CountPosition {
+        type Variant = ();
+
+        fn variant(&self) {}
+
+        fn index_count(_: &()) -> u64 {
+            LAST as u64 + 1
+        }
+
+        fn to_index(&self) -> u64 {
+            self.count as u64
+        }
+
+        fn from_index(_: &(), index: u64) -> Self {
+            CountPosition {
+                count: index as u32,
+            }
+        }
+    }
+
+    impl Position<CountMove, CountPrimitiveValue> for CountPosition {
+        fn do_move(&self, mov: CountMove) -> CountPosition {
+            CountPosition {
+                count: self.count + mov.amount,
+            }
+        }
+
+        fn generate_moves(&self) -> Vec<CountMove> {
+            (1..=2)
+                .filter(|amount| self.count + amount <= LAST)
+                .map(|amount| CountMove { amount })
+                .collect()
+        }
+
+        fn primitive_value(&self) -> CountPrimitiveValue {
+            CountPrimitiveValue {
+                is_lose: self.count == LAST,
+            }
+        }
+    }
+
+    #[test]
+    fn discarding_tiers_skipped_by_moves() {
+        for count in 0..=LAST {
+            let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+            let mut tiered = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+            assert_eq!(
+                recursive.solve(CountPosition { count }),
+                tiered
+                    .solve_by_tier_discarding(CountPosition { count })
+                    .unwrap(),
+            );
+            assert_eq!(1, tiered.memoized_map.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::connect_four::ConnectFourPosition;
+    use crate::games::order_and_chaos::{OrderAndChaosGame, OrderAndChaosPositionVEq};
+    use crate::games::take_10_to_0;
+    use crate::games::take_25_to_0;
+    use crate::games::tic_tac_toe::{TicTacToePosition, TicTacToePositionD4Eq};
+    use crate::games::tic_tac_toe_non_sq::{
+        TicTacToeNonSqPlayer, TicTacToeNonSqPosition, TicTacToeNonSqPositionVEq,
+    };
+    use crate::solver::{GameResult, GameResultWithRmt, Solver};
+
+    #[test]
+    fn same_as_recursive_subtraction_games() {
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || take_10_to_0::position(100);
+
+        assert_eq!(recursive.solve(start()), tiered.solve_by_tier(start()));
+        assert_eq!(recursive.memoized_map, tiered.memoized_map);
+
+        let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        for i in 0..=25 {
+            assert_eq!(
+                recursive.solve(take_25_to_0::position(i)),
+                tiered.solve_by_tier(take_25_to_0::position(i)),
+            );
+        }
+        assert_eq!(recursive.memoized_map, tiered.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_tic_tac_toe() {
+        let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        assert_eq!(
+            recursive.solve(TicTacToePosition::start()),
+            tiered.solve_by_tier(TicTacToePosition::start()),
+        );
+        assert_eq!(recursive.memoized_map, tiered.memoized_map);
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || TicTacToePositionD4Eq::new(TicTacToePosition::start());
+
+        assert_eq!(recursive.solve(start()), tiered.solve_by_tier(start()));
+        assert_eq!(recursive.memoized_map, tiered.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_tic_tac_toe_non_sq() {
+        let start = || {
+            TicTacToeNonSqPositionVEq::new(TicTacToeNonSqPosition {
+                board: [[None; 4]; 3],
+                player: TicTacToeNonSqPlayer::X,
+            })
+        };
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(recursive.solve(start()), tiered.solve_by_tier(start()));
+        assert_eq!(recursive.memoized_map, tiered.memoized_map);
+    }
+
+    #[test]
+    fn same_as_recursive_order_and_chaos() {
+        let start = || OrderAndChaosPositionVEq::new(OrderAndChaosGame::new(3, 3, 3).start());
+
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(recursive.solve(start()), tiered.solve_by_tier(start()));
+        assert_eq!(recursive.memoized_map, tiered.memoized_map);
+    }
+
+    #[test]
+    fn discarding_tiers() {
+        let mut recursive = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        assert_eq!(
+            recursive.solve(TicTacToePosition::start()),
+            tiered
+                .solve_by_tier_discarding(TicTacToePosition::start())
+                .unwrap(),
+        );
+        assert_eq!(1, tiered.memoized_map.len());
+
+        let mut recursive = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut tiered = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        let start = || ConnectFourPosition::start(4, 3, 3);
+
+        assert_eq!(
+            recursive.solve(start()),
+            tiered.solve_by_tier_discarding(start()).unwrap(),
+        );
+        assert_eq!(1, tiered.memoized_map.len());
+    }
+}
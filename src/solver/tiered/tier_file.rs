@@ -0,0 +1,95 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::solver::PositionHash;
+
+static NEXT_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Tiers spilled to a temporary file as `PositionHash` indices, one `u64`
+/// each, and read back farthest first. Every tier is pushed before the
+/// first is popped. The file is removed on drop.
+pub(super) struct TierFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    /// (distance, first byte, position count) of every tier not yet read
+    /// back, in the order written.
+    tiers: Vec<(u32, u64, usize)>,
+    len: u64,
+}
+
+impl TierFile {
+    pub(super) fn create() -> io::Result<TierFile> {
+        let path = std::env::temp_dir().join(format!(
+            "gamescrafters-tiers-{}-{}",
+            process::id(),
+            NEXT_FILE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let file = File::options()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+
+        Ok(TierFile {
+            path,
+            writer: BufWriter::new(file),
+            tiers: vec![],
+            len: 0,
+        })
+    }
+
+    /// Appends the tier `distance` tiers away from the start.
+    pub(super) fn push<P: PositionHash>(
+        &mut self,
+        distance: u32,
+        positions: &[P],
+    ) -> io::Result<()> {
+        for position in positions {
+            self.writer.write_all(&position.to_index().to_le_bytes())?;
+        }
+
+        self.tiers.push((distance, self.len, positions.len()));
+        self.len += positions.len() as u64 * 8;
+
+        Ok(())
+    }
+
+    /// Reads back the last tier pushed, or `None` once every tier is read.
+    pub(super) fn pop<P: PositionHash>(
+        &mut self,
+        variant: &P::Variant,
+    ) -> io::Result<Option<(u32, Vec<P>)>> {
+        let Some((distance, offset, count)) = self.tiers.pop() else {
+            return Ok(None);
+        };
+
+        self.writer.flush()?;
+
+        let file = self.writer.get_mut();
+        let mut bytes = vec![0; count * 8];
+
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut bytes)?;
+
+        let positions = bytes
+            .chunks_exact(8)
+            .map(|chunk| {
+                let index = u64::from_le_bytes(chunk.try_into().expect("chunk is 8 bytes"));
+
+                P::from_index(variant, index)
+            })
+            .collect();
+
+        Ok(Some((distance, positions)))
+    }
+}
+
+impl Drop for TierFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
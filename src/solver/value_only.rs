@@ -0,0 +1,186 @@
+use super::{
+    GameResult, MemoStore, PlayerMove, Position, PositionKey, PrimitiveValue, RecursiveValue,
+    Solver, ToGameResult, ToRecursiveValue,
+};
+
+impl<P, M, PV, RV, S> Solver<P, M, PV, RV, S>
+where
+    P: Position<M, PV> + PositionKey,
+    M: PlayerMove,
+    PV: PrimitiveValue + ToRecursiveValue<RV>,
+    RV: RecursiveValue + ToGameResult,
+    S: MemoStore<P, RV>,
+{
+    /// Returns (result, is_partial).
+    fn solve_value_only_not_memoized(&mut self, position: &P) -> (RV, bool) {
+        if let Some(result) = position.primitive_value().to_recursive_value() {
+            return (result, false);
+        }
+
+        let children = self.children(position);
+        let children_count = children.len();
+
+        let mut children_results = vec![];
+        let mut is_partial = false;
+
+        for child in children {
+            let (result, is_child_partial) = self.solve_value_only_with_partial(child);
+
+            let is_decided = result.to_game_result() == GameResult::Lose;
+
+            children_results.push(result);
+            is_partial |= is_child_partial;
+
+            if is_decided {
+                is_partial |= !RV::IS_GAME_RESULT_ONLY && children_results.len() < children_count;
+                break;
+            }
+        }
+
+        (RV::recursion_step(&children_results), is_partial)
+    }
+
+    fn solve_value_only_with_partial(&mut self, position: P) -> (RV, bool) {
+        if let Some(result) = self.memoized_map.get(&position) {
+            return (result.clone(), false);
+        }
+
+        if let Some(result) = self.partial_map.get(&position) {
+            return (result.clone(), true);
+        }
+
+        let (result, is_partial) = self.solve_value_only_not_memoized(&position);
+
+        match is_partial {
+            true => {
+                self.partial_map.insert(position, result.clone());
+            }
+            false => self.memoize(position, result.clone()),
+        }
+
+        (result, is_partial)
+    }
+
+    /// Like `solve`, but stops looking at siblings as soon as a `Lose` child
+    /// proves the position a `Win`.
+    ///
+    /// The `GameResult` of every returned value is exact, but anything else
+    /// it carries (e.g. remoteness) may not be. Such values are kept as
+    /// partial entries until `solve` or `complete_partial` redoes them, so
+    /// with plain `GameResult` nothing is ever partial.
+    pub fn solve_value_only(&mut self, position: P) -> RV {
+        let (result, _) = self.solve_value_only_with_partial(position);
+
+        result
+    }
+
+    pub fn is_partial(&self, position: &P) -> bool {
+        self.partial_map.contains_key(position)
+    }
+
+    /// Fully solves every partial entry.
+    pub fn complete_partial(&mut self) {
+        let positions = self
+            .partial_map
+            .drain()
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        for position in positions {
+            self.solve(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_with_games {
+    use std::collections::HashMap;
+
+    use crate::games::order_and_chaos::{OrderAndChaosGame, OrderAndChaosPositionD4Eq};
+    use crate::games::take_10_to_0;
+    use crate::games::tic_tac_toe::TicTacToePosition;
+    use crate::solver::{GameResult, GameResultWithRmt, RmtU32, Solver, ToGameResult};
+
+    #[test]
+    fn tic_tac_toe() {
+        let mut full = Solver::<_, _, _, GameResult>::new(HashMap::new());
+        let mut value_only = Solver::<_, _, _, GameResult>::new(HashMap::new());
+
+        let result = value_only.solve_value_only(TicTacToePosition::start());
+
+        assert_eq!(result, GameResult::Tie);
+        assert_eq!(result, full.solve(TicTacToePosition::start()));
+
+        let explored = value_only.memoized_map.len() + value_only.partial_map.len();
+        assert!(explored < full.memoized_map.len());
+
+        assert!(value_only.partial_map.is_empty());
+
+        for (position, result) in value_only.memoized_map.iter() {
+            assert_eq!(Some(result), full.memoized_map.get(position));
+        }
+    }
+
+    #[test]
+    fn order_and_chaos() {
+        let start = || OrderAndChaosPositionD4Eq::new(OrderAndChaosGame::new(3, 3, 3).start());
+
+        let mut full = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut value_only = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let result = value_only.solve_value_only(start());
+
+        assert_eq!(result.game_result, full.solve(start()).game_result);
+        assert!(
+            value_only.memoized_map.len() + value_only.partial_map.len() < full.memoized_map.len()
+        );
+
+        for (position, result) in value_only.partial_map.iter() {
+            assert_eq!(
+                result.to_game_result(),
+                full.memoized_map[position].game_result
+            );
+        }
+    }
+
+    #[test]
+    fn complete_partial() {
+        let mut full = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+        let mut value_only = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || take_10_to_0::position(10);
+
+        // Take1 first: 9 is a Lose, so Take2 is never looked at from 10.
+        assert_eq!(
+            value_only.solve_value_only(start()),
+            GameResultWithRmt {
+                game_result: GameResult::Win,
+                rmt: RmtU32::Val(7),
+            }
+        );
+        assert!(value_only.is_partial(&start()));
+
+        full.solve(start());
+        value_only.complete_partial();
+
+        assert!(value_only.partial_map.is_empty());
+        assert!(!value_only.is_partial(&start()));
+
+        for (position, result) in full.memoized_map.iter() {
+            assert_eq!(Some(result), value_only.memoized_map.get(position));
+        }
+    }
+
+    #[test]
+    fn solve_completes_partial_entry() {
+        let mut solver = Solver::<_, _, _, GameResultWithRmt>::new(HashMap::new());
+
+        let start = || take_10_to_0::position(10);
+
+        solver.solve_value_only(start());
+        assert!(solver.is_partial(&start()));
+
+        solver.solve(start());
+        assert!(!solver.is_partial(&start()));
+    }
+}